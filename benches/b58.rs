@@ -0,0 +1,70 @@
+//! Compares the `u128` Base58 codec of this crate with the generic `bs58` one.
+//!
+//! Run with `cargo bench --bench b58`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use uuid::Uuid;
+use uuid_extra::{EncodedB58, from_b58, new_v4, new_v7};
+
+fn fx_uuids() -> Vec<Uuid> {
+	let mut uuids: Vec<Uuid> = (0..64).map(|i| if i % 2 == 0 { new_v4() } else { new_v7() }).collect();
+	uuids.push(Uuid::nil());
+	uuids.push(Uuid::max());
+	uuids
+}
+
+fn bench_encode(c: &mut Criterion) {
+	let uuids = fx_uuids();
+	let mut group = c.benchmark_group("b58_encode");
+
+	group.bench_function("uuid_extra_string", |b| {
+		b.iter(|| {
+			for uuid in &uuids {
+				black_box(uuid_extra::Encoding::B58.encode(black_box(uuid)));
+			}
+		})
+	});
+	group.bench_function("uuid_extra_stack", |b| {
+		b.iter(|| {
+			for uuid in &uuids {
+				black_box(EncodedB58::new(black_box(uuid)));
+			}
+		})
+	});
+	group.bench_function("bs58_string", |b| {
+		b.iter(|| {
+			for uuid in &uuids {
+				black_box(bs58::encode(black_box(uuid).as_bytes()).into_string());
+			}
+		})
+	});
+
+	group.finish();
+}
+
+fn bench_decode(c: &mut Criterion) {
+	let encoded: Vec<String> = fx_uuids().iter().map(|uuid| EncodedB58::new(uuid).to_string()).collect();
+	let mut group = c.benchmark_group("b58_decode");
+
+	group.bench_function("uuid_extra", |b| {
+		b.iter(|| {
+			for s in &encoded {
+				black_box(from_b58(black_box(s)).expect("valid base58"));
+			}
+		})
+	});
+	group.bench_function("bs58_vec", |b| {
+		b.iter(|| {
+			for s in &encoded {
+				let bytes = bs58::decode(black_box(s)).into_vec().expect("valid base58");
+				black_box(Uuid::from_slice(&bytes).expect("16 bytes"));
+			}
+		})
+	});
+
+	group.finish();
+}
+
+criterion_group!(benches, bench_encode, bench_decode);
+criterion_main!(benches);
@@ -0,0 +1,461 @@
+//! `uuid-extra` command-line tool to generate, convert, and inspect encoded UUIDs (requires the `cli` feature).
+//!
+//! ```sh
+//! uuid-extra gen --version v7 --enc b58 --count 3
+//! uuid-extra convert --to hyphenated Cg78pMW36DNZ39wPvomBQ
+//! cat ids.log | uuid-extra convert --to hyphenated --json
+//! uuid-extra inspect 01H3Q2MVTEF8NSRF8Y5WX4PQ3D
+//! ```
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde_json::json;
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+use uuid::Uuid;
+use uuid_extra::{
+	Encoding, UuidGenerator, from_b58, new_v1, new_v6, parse_any_of, random_node_id, to_any_time_epoch_ms,
+};
+
+// region:    --- Args
+
+#[derive(Parser)]
+#[command(name = "uuid-extra", version, about = "Generate, convert, and inspect encoded UUIDs")]
+struct Cli {
+	/// Print one JSON object per line.
+	#[arg(long, global = true)]
+	json: bool,
+
+	#[command(subcommand)]
+	command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// Generate new UUIDs.
+	Gen {
+		/// UUID version.
+		#[arg(long, value_enum, default_value_t = VersionArg::V7)]
+		version: VersionArg,
+
+		/// Output encoding.
+		#[arg(long, value_enum, default_value_t = EncArg::B58)]
+		enc: EncArg,
+
+		/// Number of UUIDs to generate.
+		#[arg(long, default_value_t = 1)]
+		count: usize,
+	},
+
+	/// Convert UUIDs to another encoding, auto-detecting the input encoding.
+	Convert {
+		/// Output encoding.
+		#[arg(long, value_enum)]
+		to: EncArg,
+
+		/// Input encoding, to read an encoding that is not auto-detected (e.g., Base62).
+		#[arg(long, value_enum)]
+		from: Option<EncArg>,
+
+		#[command(flatten)]
+		inputs: InputArgs,
+	},
+
+	/// Print the version, variant, all encodings, and the timestamp (v1, v6, v7).
+	Inspect {
+		/// Input encoding, to read an encoding that is not auto-detected (e.g., Base62).
+		#[arg(long, value_enum)]
+		from: Option<EncArg>,
+
+		#[command(flatten)]
+		inputs: InputArgs,
+	},
+}
+
+#[derive(Args)]
+struct InputArgs {
+	/// UUIDs in any supported encoding (reads stdin line by line when empty).
+	inputs: Vec<String>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum VersionArg {
+	V1,
+	V4,
+	V6,
+	V7,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum EncArg {
+	Hyphenated,
+	Simple,
+	Braced,
+	Urn,
+	B58,
+	#[value(name = "b58-sortable")]
+	B58Sortable,
+	B62,
+	B32,
+	B64,
+	B64url,
+	#[value(name = "b64url-nopad")]
+	B64urlNopad,
+}
+
+impl From<EncArg> for Encoding {
+	fn from(enc: EncArg) -> Self {
+		match enc {
+			EncArg::Hyphenated => Encoding::Hyphenated,
+			EncArg::Simple => Encoding::Simple,
+			EncArg::Braced => Encoding::Braced,
+			EncArg::Urn => Encoding::Urn,
+			EncArg::B58 => Encoding::B58,
+			EncArg::B58Sortable => Encoding::B58Sortable,
+			EncArg::B62 => Encoding::B62,
+			EncArg::B32 => Encoding::B32,
+			EncArg::B64 => Encoding::B64,
+			EncArg::B64url => Encoding::B64Url,
+			EncArg::B64urlNopad => Encoding::B64UrlNoPad,
+		}
+	}
+}
+
+// endregion: --- Args
+
+fn main() -> ExitCode {
+	let cli = Cli::parse();
+	let mut out = io::stdout().lock();
+
+	let res = match cli.command {
+		Command::Gen { version, enc, count } => exec_gen(&mut out, version, enc.into(), count, cli.json),
+		Command::Convert { to, from, inputs } => for_each_input(inputs, from, |input, uuid, detected| {
+			write_convert(&mut out, input, uuid, detected, to.into(), cli.json)
+		}),
+		Command::Inspect { from, inputs } => for_each_input(inputs, from, |input, uuid, detected| {
+			write_inspect(&mut out, input, uuid, detected, cli.json)
+		}),
+	};
+
+	match res {
+		Ok(true) => ExitCode::SUCCESS,
+		Ok(false) => ExitCode::FAILURE,
+		Err(err) => {
+			eprintln!("error: {err}");
+			ExitCode::FAILURE
+		}
+	}
+}
+
+// region:    --- Commands
+
+type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+fn exec_gen(out: &mut impl Write, version: VersionArg, enc: Encoding, count: usize, json: bool) -> Result<bool> {
+	let mut generator = UuidGenerator::new();
+	let node_id = random_node_id();
+	for _ in 0..count {
+		let uuid = match version {
+			VersionArg::V1 => new_v1(&node_id),
+			VersionArg::V4 => generator.new_v4(),
+			VersionArg::V6 => new_v6(&node_id),
+			VersionArg::V7 => generator.new_v7(),
+		};
+		let encoded = enc.encode(&uuid);
+		if json {
+			let value = json!({ "id": encoded, "uuid": uuid.hyphenated().to_string() });
+			writeln!(out, "{value}")?;
+		} else {
+			writeln!(out, "{encoded}")?;
+		}
+	}
+	Ok(true)
+}
+
+/// Calls `f` for each input (args, or stdin lines), reporting the parse errors
+/// (and the ambiguous inputs read as Base58) on stderr.
+///
+/// Returns `Ok(false)` if at least one input failed to parse.
+fn for_each_input(
+	inputs: InputArgs,
+	from: Option<EncArg>,
+	mut f: impl FnMut(&str, &Uuid, Encoding) -> Result<()>,
+) -> Result<bool> {
+	let mut all_ok = true;
+	let mut handle = |input: &str| -> Result<()> {
+		let input = input.trim();
+		if input.is_empty() {
+			return Ok(());
+		}
+		match parse_input(input, from) {
+			Ok((uuid, detected, ambiguous_with)) => {
+				if let Some(candidates) = ambiguous_with {
+					let names: Vec<&str> = candidates.iter().map(Encoding::name).collect();
+					eprintln!(
+						"warning: '{input}' - ambiguous encoding ({}), read as {}, use --from to choose",
+						names.join(", "),
+						detected.name()
+					);
+				}
+				f(input, &uuid, detected)?
+			}
+			Err(err) => {
+				all_ok = false;
+				eprintln!("error: '{input}' - {err}");
+			}
+		}
+		Ok(())
+	};
+
+	if inputs.inputs.is_empty() {
+		for line in io::stdin().lock().lines() {
+			handle(&line?)?;
+		}
+	} else {
+		for input in inputs.inputs.iter() {
+			handle(input)?;
+		}
+	}
+
+	Ok(all_ok)
+}
+
+/// Parses an input with the `--from` encoding, or auto-detects it among `AUTO_DETECT`.
+///
+/// When auto-detection is ambiguous, Base58 (the default `gen` encoding) wins,
+/// and the candidates are returned so that the caller can warn.
+fn parse_input(input: &str, from: Option<EncArg>) -> uuid_extra::Result<(Uuid, Encoding, Option<Vec<Encoding>>)> {
+	let Some(from) = from else {
+		return match parse_any_of(input, &AUTO_DETECT) {
+			Ok((uuid, detected)) => Ok((uuid, detected, None)),
+			Err(uuid_extra::Error::AmbiguousEncoding { candidates }) if candidates.contains(&Encoding::B58) => {
+				Ok((from_b58(input)?, Encoding::B58, Some(candidates)))
+			}
+			Err(err) => Err(err),
+		};
+	};
+	let (uuid, detected) = parse_any_of(input, &[from.into()])?;
+	Ok((uuid, detected, None))
+}
+
+fn write_convert(
+	out: &mut impl Write,
+	input: &str,
+	uuid: &Uuid,
+	from: Encoding,
+	to: Encoding,
+	json: bool,
+) -> Result<()> {
+	let encoded = to.encode(uuid);
+	if json {
+		let value = json!({ "input": input, "from": from.name(), "to": to.name(), "output": encoded });
+		writeln!(out, "{value}")?;
+	} else {
+		writeln!(out, "{encoded}")?;
+	}
+	Ok(())
+}
+
+fn write_inspect(out: &mut impl Write, input: &str, uuid: &Uuid, detected: Encoding, json: bool) -> Result<()> {
+	let version = uuid.get_version_num();
+	let variant = format!("{:?}", uuid.get_variant());
+	let epoch_ms = to_any_time_epoch_ms(uuid).ok();
+	let timestamp = epoch_ms.map(rfc3339_from_epoch_ms);
+
+	if json {
+		let encodings: serde_json::Map<String, serde_json::Value> = Encoding::ALL
+			.iter()
+			.map(|enc| (enc.name().to_string(), enc.encode(uuid).into()))
+			.collect();
+		let value = json!({
+			"input": input,
+			"detected": detected.name(),
+			"version": version,
+			"variant": variant,
+			"epoch_ms": epoch_ms,
+			"timestamp": timestamp,
+			"encodings": encodings,
+		});
+		writeln!(out, "{value}")?;
+	} else {
+		writeln!(out, "{:<16} {input}", "input:")?;
+		writeln!(out, "{:<16} {}", "detected:", detected.name())?;
+		writeln!(out, "{:<16} {version}", "version:")?;
+		writeln!(out, "{:<16} {variant}", "variant:")?;
+		if let (Some(epoch_ms), Some(timestamp)) = (epoch_ms, timestamp) {
+			writeln!(out, "{:<16} {timestamp} ({epoch_ms} ms)", "timestamp:")?;
+		}
+		for enc in Encoding::ALL {
+			writeln!(out, "{:<16} {}", format!("{}:", enc.name()), enc.encode(uuid))?;
+		}
+		writeln!(out)?;
+	}
+	Ok(())
+}
+
+// endregion: --- Commands
+
+// region:    --- Support
+
+/// Encodings auto-detected without `--from`, the `parse_any` ones and Crockford Base32 (26 chars, not ambiguous).
+const AUTO_DETECT: [Encoding; 9] = [
+	Encoding::Hyphenated,
+	Encoding::Simple,
+	Encoding::Braced,
+	Encoding::Urn,
+	Encoding::B58,
+	Encoding::B32,
+	Encoding::B64,
+	Encoding::B64Url,
+	Encoding::B64UrlNoPad,
+];
+
+/// Formats epoch milliseconds as an RFC 3339 UTC timestamp (e.g., `2023-11-14T22:13:20.000Z`).
+fn rfc3339_from_epoch_ms(epoch_ms: i64) -> String {
+	let days = epoch_ms.div_euclid(86_400_000);
+	let ms_of_day = epoch_ms.rem_euclid(86_400_000);
+	let (year, month, day) = civil_from_days(days);
+
+	let (hour, min) = (ms_of_day / 3_600_000, ms_of_day / 60_000 % 60);
+	let (sec, ms) = (ms_of_day / 1_000 % 60, ms_of_day % 1_000);
+	format!("{year:04}-{month:02}-{day:02}T{hour:02}:{min:02}:{sec:02}.{ms:03}Z")
+}
+
+/// Converts days since 1970-01-01 to a `(year, month, day)` proleptic Gregorian date
+/// (Howard Hinnant's `civil_from_days` algorithm).
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+	let z = days + 719_468;
+	let era = z.div_euclid(146_097);
+	let doe = z.rem_euclid(146_097);
+	let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = doy - (153 * mp + 2) / 5 + 1;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+	let year = yoe + era * 400 + i64::from(month <= 2);
+	(year, month, day)
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_cli_rfc3339_from_epoch_ms() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_cases = [
+			(0, "1970-01-01T00:00:00.000Z"),
+			(1_700_000_000_123, "2023-11-14T22:13:20.123Z"),
+			(951_782_400_000, "2000-02-29T00:00:00.000Z"),
+			((1 << 48) - 1, "10889-08-02T05:31:50.655Z"),
+		];
+
+		for (epoch_ms, expected) in fx_cases {
+			// -- Exec
+			let timestamp = rfc3339_from_epoch_ms(epoch_ms);
+
+			// -- Check
+			assert_eq!(timestamp, expected);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_cli_convert_and_inspect_json() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_uuid = uuid_extra::v7_from_epoch_ms(1_700_000_000_000)?;
+		let mut out: Vec<u8> = Vec::new();
+
+		// -- Exec
+		let fx_input = uuid_extra::to_b58(&fx_uuid);
+		write_convert(
+			&mut out,
+			&fx_input,
+			&fx_uuid,
+			Encoding::B58,
+			Encoding::Hyphenated,
+			false,
+		)?;
+		write_inspect(&mut out, &fx_input, &fx_uuid, Encoding::B58, true)?;
+
+		// -- Check
+		let out = String::from_utf8(out)?;
+		let mut lines = out.lines();
+		assert_eq!(lines.next(), Some(fx_uuid.hyphenated().to_string().as_str()));
+		let inspect: serde_json::Value = serde_json::from_str(lines.next().ok_or("Missing inspect line")?)?;
+		assert_eq!(inspect["version"], 7);
+		assert_eq!(inspect["timestamp"], "2023-11-14T22:13:20.000Z");
+		assert_eq!(inspect["encodings"]["base58"], uuid_extra::to_b58(&fx_uuid));
+		Ok(())
+	}
+
+	#[test]
+	fn test_cli_for_each_input_auto_detect_b58() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_b58_ids: Vec<String> = (0..2_000).map(|_| uuid_extra::new_v4_b58()).collect();
+		let fx_inputs = InputArgs {
+			inputs: fx_b58_ids.clone(),
+		};
+		let mut converted: Vec<(Uuid, Encoding)> = Vec::new();
+
+		// -- Exec
+		let all_ok = for_each_input(fx_inputs, None, |_, uuid, detected| {
+			converted.push((*uuid, detected));
+			Ok(())
+		})?;
+
+		// -- Check
+		assert!(all_ok, "All Base58 inputs should parse");
+		assert_eq!(converted.len(), fx_b58_ids.len());
+		for (b58_id, (uuid, detected)) in fx_b58_ids.iter().zip(converted) {
+			assert_eq!(
+				(uuid, detected),
+				(from_b58(b58_id)?, Encoding::B58),
+				"Wrong detection for '{b58_id}'"
+			);
+		}
+		let (b32_uuid, b32_enc, _) = parse_input("01H3Q2MVTEF8NSRF8Y5WX4PQ3D", None)?;
+		assert_eq!((b32_uuid.get_version_num(), b32_enc), (7, Encoding::B32));
+		// Both Base58 and URL-safe Base64, read as Base58 with the candidates to warn about.
+		let (_, ambiguous_enc, ambiguous_with) = parse_input("N6x9712uLC6S5GZuhuebsA", None)?;
+		assert_eq!(ambiguous_enc, Encoding::B58);
+		assert_eq!(ambiguous_with, Some(vec![Encoding::B58, Encoding::B64UrlNoPad]));
+		let (_, from_enc, from_ambiguous) = parse_input("N6x9712uLC6S5GZuhuebsA", Some(EncArg::B64urlNopad))?;
+		assert_eq!((from_enc, from_ambiguous), (Encoding::B64UrlNoPad, None));
+		Ok(())
+	}
+
+	#[test]
+	fn test_cli_args_parse() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_args = [
+			"uuid-extra",
+			"gen",
+			"--version",
+			"v4",
+			"--enc",
+			"b64url-nopad",
+			"--count",
+			"3",
+		];
+
+		// -- Exec
+		let cli = Cli::try_parse_from(fx_args)?;
+
+		// -- Check
+		assert!(matches!(
+			cli.command,
+			Command::Gen {
+				version: VersionArg::V4,
+				enc: EncArg::B64urlNopad,
+				count: 3
+			}
+		));
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
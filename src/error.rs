@@ -1,3 +1,4 @@
+use crate::{Encoding, V8Field};
 use derive_more::{Display, From};
 use uuid::Uuid;
 
@@ -15,6 +16,114 @@ pub enum Error {
 	},
 
 	FailExtractTimeNoUuidV7(Uuid),
+	FailExtractTimeNotTimeBased(Uuid),
+	UnexpectedVersion {
+		expected: u8,
+		actual: u8,
+	},
+	V7EpochMsOutOfRange {
+		epoch_ms: i128,
+	},
+	V7RangeStartAfterEnd {
+		start_ms: i64,
+		end_ms: i64,
+	},
+
+	// -- Generator
+	V7CounterBitsOutOfRange {
+		bits: u8,
+	},
+
+	// -- V8 Layout
+	V8FieldBitsOutOfRange {
+		field: V8Field,
+		bits: u8,
+	},
+	V8DuplicateField {
+		field: V8Field,
+	},
+	V8LayoutTooManyBits {
+		bits: u32,
+	},
+	V8FieldOutOfRange {
+		field: V8Field,
+		bits: u8,
+	},
+	V8FieldNotInLayout {
+		field: V8Field,
+	},
+
+	// -- Encode
+	BufferTooSmall {
+		encoding: &'static str,
+		required: usize,
+		actual: usize,
+	},
+	UnsupportedEncoding {
+		encoding: Encoding,
+	},
+
+	// -- Decode
+	InvalidCharacter {
+		encoding: &'static str,
+		ch: char,
+		index: usize,
+	},
+	InvalidLength {
+		encoding: &'static str,
+		expected: usize,
+		actual: usize,
+	},
+	InvalidPadding {
+		encoding: &'static str,
+	},
+	ValueOverflow {
+		encoding: &'static str,
+	},
+	ChecksumMismatch {
+		encoding: &'static str,
+	},
+	InvalidWord {
+		encoding: &'static str,
+		word: String,
+		index: usize,
+	},
+
+	// -- Parse
+	UnknownEncoding {
+		length: usize,
+	},
+	AmbiguousEncoding {
+		candidates: Vec<Encoding>,
+	},
+	UnknownMultibasePrefix {
+		prefix: Option<char>,
+	},
+
+	// -- Prefix (TypeID & Prefixed)
+	InvalidPrefix {
+		prefix: String,
+		reason: &'static str,
+	},
+	PrefixMismatch {
+		expected: String,
+		actual: String,
+	},
+	InvalidSeparator {
+		separator: char,
+	},
+	MissingSeparator {
+		separator: char,
+	},
+
+	// -- Signed Id
+	SignedIdTagLenOutOfRange {
+		tag_len: usize,
+	},
+	SignedIdUnknownKey {
+		key_id: u8,
+	},
+	SignedIdInvalidTag,
 
 	// -- Externals
 	#[from]
@@ -0,0 +1,360 @@
+use crate::extra_uuid::{new_v3, new_v4, new_v5, new_v7, to_time_epoch_ms};
+use crate::{EncodedB32, Error, Result, support};
+use uuid::Uuid;
+
+/// Crockford Base32 alphabet (no I, L, O, U).
+pub(crate) const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Number of Crockford Base32 characters needed for 128 bits (26 * 5 = 130 bits, top 2 bits are zero).
+pub(crate) const B32_UUID_LEN: usize = 26;
+
+// region:    --- v4
+
+/// Generates a new UUID version 4 and encodes it using Crockford Base32.
+pub fn new_v4_b32() -> String {
+	let uuid = new_v4();
+	to_b32(&uuid)
+}
+
+// endregion: --- v4
+
+// region:    --- v7
+
+/// Generates a new UUID version 7 and encodes it using Crockford Base32.
+///
+/// The output is always 26 characters, so v7 ids also sort lexicographically by time.
+pub fn new_v7_b32() -> String {
+	let uuid = new_v7();
+	to_b32(&uuid)
+}
+
+// endregion: --- v7
+
+// region:    --- v5 & v3
+
+/// Generates a UUID version 5 (SHA-1) for `name` within `namespace` and encodes it using Crockford Base32.
+pub fn new_v5_b32(namespace: &Uuid, name: impl AsRef<[u8]>) -> String {
+	let uuid = new_v5(namespace, name);
+	to_b32(&uuid)
+}
+
+/// Generates a UUID version 3 (MD5) for `name` within `namespace` and encodes it using Crockford Base32.
+pub fn new_v3_b32(namespace: &Uuid, name: impl AsRef<[u8]>) -> String {
+	let uuid = new_v3(namespace, name);
+	to_b32(&uuid)
+}
+
+// endregion: --- v5 & v3
+
+// region:    --- To String
+
+/// Encodes an existing UUID using Crockford Base32.
+///
+/// See `EncodedB32` or `encode_b32_into` to encode without allocating.
+pub fn to_b32(uuid: &Uuid) -> String {
+	EncodedB32::new(uuid).to_string()
+}
+
+// endregion: --- To String
+
+// region:    --- From String
+
+/// Decodes a Crockford Base32 encoded string into a UUID.
+///
+/// Decoding is case-insensitive, maps the confusable `I`/`L` to `1` and `O` to `0`,
+/// and ignores `-` separators.
+pub fn from_b32(s: &str) -> Result<Uuid> {
+	let mut buf = [0u8; support::DECODE_BUF_LEN];
+	let decoded_bytes = decode_crockford(s, &mut buf)?;
+	support::from_slice_u8(decoded_bytes, "base32")
+}
+
+/// Decodes a Crockford Base32 encoded string into an epoch millisecond timestamp.
+/// This function is valid only for UUID v7.
+pub fn b32_to_epoch_ms(s: &str) -> Result<i64> {
+	let uuid = from_b32(s)?;
+	to_time_epoch_ms(&uuid)
+}
+
+// endregion: --- From String
+
+// region:    --- Support
+
+/// Writes the Crockford Base32 encoding into `buf`, returning the written length.
+pub(crate) fn write_b32(uuid: &Uuid, buf: &mut [u8; B32_UUID_LEN]) -> usize {
+	let value = uuid.as_u128();
+	for (i, slot) in buf.iter_mut().enumerate() {
+		let shift = 5 * (B32_UUID_LEN - 1 - i);
+		*slot = CROCKFORD_ALPHABET[((value >> shift) & 0x1F) as usize];
+	}
+	B32_UUID_LEN
+}
+
+/// Returns the 5-bit value of a Crockford Base32 character (case-insensitive, with confusable mapping).
+pub(crate) fn crockford_digit(ch: char) -> Option<u8> {
+	let digit = match ch.to_ascii_uppercase() {
+		c @ '0'..='9' => c as u8 - b'0',
+		'O' => 0,
+		'I' | 'L' => 1,
+		c @ ('A'..='H' | 'J' | 'K' | 'M' | 'N' | 'P'..='T' | 'V'..='Z') => {
+			CROCKFORD_ALPHABET.iter().position(|&a| a == c as u8)? as u8
+		}
+		_ => return None,
+	};
+	Some(digit)
+}
+
+/// Decodes a Crockford Base32 string as a big-endian number into `buf`, returning the decoded bytes.
+///
+/// The leading bits that do not fill a full byte must be zero (e.g., the top 2 bits of a 26-char string).
+/// When they hold a whole digit (e.g., an extra leading `0`), they are kept as a byte, so the decoded
+/// length is the real one (e.g., 17 bytes for 27 chars).
+fn decode_crockford<'a>(s: &str, buf: &'a mut [u8; support::DECODE_BUF_LEN]) -> Result<&'a [u8]> {
+	let digit_count = s.chars().filter(|&ch| ch != '-').count();
+
+	// Virtual leading zero bits so that the total bit count is a multiple of 8.
+	let pad_bits = (8 - (digit_count * 5) % 8) % 8;
+
+	if (digit_count * 5 + pad_bits) / 8 > buf.len() {
+		return Err(Error::InvalidLength {
+			encoding: "base32",
+			expected: B32_UUID_LEN,
+			actual: digit_count,
+		});
+	}
+
+	let mut len = 0;
+	let mut acc: u32 = 0;
+	let mut acc_bits = pad_bits;
+	for (index, ch) in s.char_indices() {
+		if ch == '-' {
+			continue;
+		}
+		let digit = crockford_digit(ch).ok_or(Error::InvalidCharacter {
+			encoding: "base32",
+			ch,
+			index,
+		})?;
+		acc = (acc << 5) | digit as u32;
+		acc_bits += 5;
+		if acc_bits >= 8 {
+			acc_bits -= 8;
+			buf[len] = (acc >> acc_bits) as u8;
+			len += 1;
+			acc &= (1 << acc_bits) - 1;
+		}
+	}
+
+	let bytes = &buf[..len];
+	match bytes.split_first() {
+		// Less than a digit in the leading byte (e.g., 2 bits for 26 chars), it is padding only.
+		Some((&first, rest)) if pad_bits > 3 => {
+			if first != 0 {
+				return Err(Error::ValueOverflow { encoding: "base32" });
+			}
+			Ok(rest)
+		}
+		_ => Ok(bytes),
+	}
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use uuid::{Uuid, Version};
+
+	#[test]
+	fn test_extra_base32_new_v4_b32_simple() -> Result<()> {
+		// -- Setup & Fixtures
+		// (no specific setup needed for this test)
+
+		// -- Exec
+		let b32_uuid = new_v4_b32();
+
+		// -- Check
+		assert_eq!(b32_uuid.len(), 26, "Crockford Base32 of UUID should be 26 chars");
+		assert!(
+			b32_uuid.bytes().all(|b| CROCKFORD_ALPHABET.contains(&b)),
+			"Crockford Base32 should only contain alphabet characters. Got: {b32_uuid}"
+		);
+		let uuid = from_b32(&b32_uuid)?;
+		assert_eq!(uuid.get_version(), Some(Version::Random));
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base32_new_v7_b32_simple() -> Result<()> {
+		// -- Setup & Fixtures
+		// (no specific setup needed for this test)
+
+		// -- Exec
+		let b32_uuid = new_v7_b32();
+
+		// -- Check
+		assert_eq!(b32_uuid.len(), 26, "Crockford Base32 of V7 UUID should be 26 chars");
+		let uuid = from_b32(&b32_uuid)?;
+		assert_eq!(uuid.get_version(), Some(Version::SortRand));
+
+		Ok(())
+	}
+
+	// region:    --- Tests for from_... functions
+
+	#[test]
+	fn test_extra_base32_from_b32_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let original_uuid = Uuid::from_u128(0x0188_ee2a_6f4e_7a2b_9c3d_1e2f_3a4b_5c6d);
+		let b32_string = to_b32(&original_uuid);
+
+		// -- Exec
+		let decoded_uuid = from_b32(&b32_string)?;
+
+		// -- Check
+		assert_eq!(b32_string, "01H3Q2MVTEF8NSRF8Y5WX4PQ3D");
+		assert_eq!(decoded_uuid, original_uuid, "Decoded UUID should match original");
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base32_from_b32_ok_lowercase_and_confusables() -> Result<()> {
+		// -- Setup & Fixtures
+		let original_uuid = new_v4();
+		let b32_string = to_b32(&original_uuid);
+		let spoken_string = b32_string.to_lowercase().replace('0', "o").replace('1', "l");
+		let hyphenated_string = format!("{}-{}", &b32_string[..13], &b32_string[13..]);
+
+		// -- Exec
+		let from_spoken = from_b32(&spoken_string)?;
+		let from_hyphenated = from_b32(&hyphenated_string)?;
+
+		// -- Check
+		assert_eq!(from_spoken, original_uuid);
+		assert_eq!(from_hyphenated, original_uuid);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base32_from_b32_err_invalid_char() -> Result<()> {
+		// -- Setup & Fixtures
+		let invalid_b32_string = "01H3Q2MVTEF8NSRF8Y5WX4PQ3U"; // 'U' is not a Crockford character
+
+		// -- Exec
+		let decoded_uuid_res = from_b32(invalid_b32_string);
+
+		// -- Check
+		assert!(
+			matches!(
+				decoded_uuid_res,
+				Err(Error::InvalidCharacter {
+					encoding: "base32",
+					ch: 'U',
+					index: 25
+				})
+			),
+			"Expected InvalidCharacter, got {decoded_uuid_res:?}"
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base32_from_b32_err_wrong_len() -> Result<()> {
+		// -- Setup & Fixtures
+		let short_b32_string = "CSQPYRK1"; // 8 chars, decodes to 5 bytes
+
+		// -- Exec
+		let decoded_uuid_res = from_b32(short_b32_string);
+
+		// -- Check
+		match decoded_uuid_res {
+			Err(Error::FailToDecode16U8 { context, actual_length }) => {
+				assert_eq!(context, "base32", "Error context should be 'base32'");
+				assert_eq!(actual_length, 5, "Actual length should be 5");
+			}
+			other => panic!("Expected FailToDecode16U8, got {other:?}"),
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base32_from_b32_err_extra_leading_zeros() -> Result<()> {
+		// -- Setup & Fixtures
+		let long_b32_string = "001H3Q2MVTEF8NSRF8Y5WX4PQ3D"; // 27 chars, decodes to 17 bytes
+
+		// -- Exec
+		let decoded_uuid_res = from_b32(long_b32_string);
+
+		// -- Check
+		assert!(
+			matches!(
+				decoded_uuid_res,
+				Err(Error::FailToDecode16U8 {
+					context: "base32",
+					actual_length: 17
+				})
+			),
+			"Expected FailToDecode16U8, got {decoded_uuid_res:?}"
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base32_from_b32_err_overflow() -> Result<()> {
+		// -- Setup & Fixtures
+		let overflow_b32_string = "81H3Q2MVTEF8NSRF8Y5WX4PQ3D"; // first char above '7' needs more than 128 bits
+
+		// -- Exec
+		let decoded_uuid_res = from_b32(overflow_b32_string);
+
+		// -- Check
+		assert!(
+			matches!(decoded_uuid_res, Err(Error::ValueOverflow { encoding: "base32" })),
+			"Expected ValueOverflow, got {decoded_uuid_res:?}"
+		);
+		Ok(())
+	}
+
+	// endregion: --- Tests for from_... functions
+
+	#[test]
+	fn test_extra_base32_b32_to_epoch_ms_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let original_uuid = new_v7();
+		let b32_string = to_b32(&original_uuid);
+		let original_ts = to_time_epoch_ms(&original_uuid)?;
+
+		// -- Exec
+		let extracted_ts = b32_to_epoch_ms(&b32_string)?;
+
+		// -- Check
+		assert_eq!(extracted_ts, original_ts);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base32_b32_to_epoch_ms_err_not_v7() -> Result<()> {
+		// -- Setup & Fixtures
+		let uuid_v4 = new_v4();
+		let b32_string = to_b32(&uuid_v4);
+
+		// -- Exec
+		let result = b32_to_epoch_ms(&b32_string);
+
+		// -- Check
+		match result {
+			Err(Error::FailExtractTimeNoUuidV7(id)) => {
+				assert_eq!(id, uuid_v4);
+			}
+			_ => panic!("Expected FailExtractTimeNoUuidV7 error"),
+		}
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
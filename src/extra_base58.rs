@@ -1,13 +1,36 @@
-use crate::extra_uuid::{new_v4, new_v7, to_time_epoch_ms};
-use crate::{Error, Result, support};
+use crate::extra_uuid::{new_v3, new_v4, new_v5, new_v7, to_time_epoch_ms, v7_from_epoch_ms};
+use crate::{EncodedB58, EncodedB58Sortable, Error, Result};
 use uuid::Uuid;
 
+/// Bitcoin Base58 alphabet (same as `bs58` default), which is in ASCII order.
+pub(crate) const B58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Number of Base58 characters needed for 128 bits (58^22 > 2^128).
+pub(crate) const B58_SORTABLE_LEN: usize = 22;
+
+/// Longest Base58 (`bs58`) encoding of 16 bytes (small values give shorter strings).
+pub(crate) const B58_MAX_LEN: usize = 22;
+
+/// 58^10, the largest power of 58 fitting in a `u64`, to encode by chunks of 10 digits.
+const B58_POW_10: u128 = 430_804_206_899_405_824;
+
+/// Reverse lookup of `B58_ALPHABET` for the ASCII range (`0xFF` for invalid).
+const B58_DIGITS: [u8; 128] = {
+	let mut digits = [0xFF; 128];
+	let mut i = 0;
+	while i < B58_ALPHABET.len() {
+		digits[B58_ALPHABET[i] as usize] = i as u8;
+		i += 1;
+	}
+	digits
+};
+
 // region:    --- v4
 
 /// Generates a new UUID version 4 and encodes it using Base58.
 pub fn new_v4_b58() -> String {
 	let uuid = new_v4();
-	bs58::encode(uuid.as_bytes()).into_string()
+	to_b58(&uuid)
 }
 
 // endregion: --- v4
@@ -17,17 +40,102 @@ pub fn new_v4_b58() -> String {
 /// Generates a new UUID version 7 and encodes it using Base58.
 pub fn new_v7_b58() -> String {
 	let uuid = new_v7();
-	bs58::encode(uuid.as_bytes()).into_string()
+	to_b58(&uuid)
+}
+
+/// Generates a new UUID version 7 and encodes it using fixed-width, order-preserving Base58.
+///
+/// Unlike `new_v7_b58`, the output is always 22 characters (left-padded with `'1'`),
+/// so comparing the strings gives the same order as comparing the UUIDs.
+///
+/// Note: This is not compatible with `from_b58`; decode it with `from_b58_sortable`.
+pub fn new_v7_b58_sortable() -> String {
+	let uuid = new_v7();
+	to_b58_sortable(&uuid)
+}
+
+/// Generates a new UUID version 7 for the given epoch milliseconds and encodes it using Base58.
+pub fn v7_from_epoch_ms_b58(epoch_ms: i64) -> Result<String> {
+	let uuid = v7_from_epoch_ms(epoch_ms)?;
+	Ok(to_b58(&uuid))
 }
 
 // endregion: --- v7
 
+// region:    --- v5 & v3
+
+/// Generates a UUID version 5 (SHA-1) for `name` within `namespace` and encodes it using Base58.
+pub fn new_v5_b58(namespace: &Uuid, name: impl AsRef<[u8]>) -> String {
+	let uuid = new_v5(namespace, name);
+	to_b58(&uuid)
+}
+
+/// Generates a UUID version 3 (MD5) for `name` within `namespace` and encodes it using Base58.
+pub fn new_v3_b58(namespace: &Uuid, name: impl AsRef<[u8]>) -> String {
+	let uuid = new_v3(namespace, name);
+	to_b58(&uuid)
+}
+
+// endregion: --- v5 & v3
+
+// region:    --- To String
+
+/// Encodes an existing UUID using Base58.
+///
+/// See `EncodedB58` or `encode_b58_into` to encode without allocating.
+pub fn to_b58(uuid: &Uuid) -> String {
+	EncodedB58::new(uuid).to_string()
+}
+
+/// Encodes an existing UUID using fixed-width, order-preserving Base58 (see `new_v7_b58_sortable`).
+///
+/// See `EncodedB58Sortable` or `encode_b58_sortable_into` to encode without allocating.
+pub fn to_b58_sortable(uuid: &Uuid) -> String {
+	EncodedB58Sortable::new(uuid).to_string()
+}
+
+// endregion: --- To String
+
 // region:    --- From String
 
 /// Decodes a Base58 encoded string into a UUID.
+///
+/// Same as decoding with `bs58` (each leading `'1'` is a zero byte), but with `u128` arithmetic
+/// since the payload is always 16 bytes.
 pub fn from_b58(s: &str) -> Result<Uuid> {
-	let decoded_bytes = bs58::decode(s).into_vec().map_err(Error::custom_from_err)?;
-	support::from_vec_u8(decoded_bytes, "base58")
+	if s.len() > B58_MAX_LEN {
+		return Err(Error::InvalidLength {
+			encoding: "base58",
+			expected: B58_MAX_LEN,
+			actual: s.len(),
+		});
+	}
+
+	let zeros = s.bytes().take_while(|&b| b == B58_ALPHABET[0]).count();
+
+	// Note: Keep validating the characters after an overflow, so invalid characters are reported first.
+	let mut value: Option<u128> = Some(0);
+	for (index, ch) in s.char_indices().skip(zeros) {
+		let digit = b58_digit(ch).ok_or(Error::InvalidCharacter {
+			encoding: "base58",
+			ch,
+			index,
+		})?;
+		value = value.and_then(|v| v.checked_mul(58)).and_then(|v| v.checked_add(digit as u128));
+	}
+	let value = value.ok_or(Error::ValueOverflow { encoding: "base58" })?;
+
+	// Same byte length as `bs58` would decode, for the error.
+	let value_len = (128 - value.leading_zeros() as usize).div_ceil(8);
+	let actual_length = zeros + value_len;
+	if actual_length != 16 {
+		return Err(Error::FailToDecode16U8 {
+			context: "base58",
+			actual_length,
+		});
+	}
+
+	Ok(Uuid::from_u128(value))
 }
 
 /// Decodes a Base58 encoded string into an epoch millisecond timestamp.
@@ -37,8 +145,156 @@ pub fn b58_to_epoch_ms(s: &str) -> Result<i64> {
 	to_time_epoch_ms(&uuid)
 }
 
+/// Decodes a fixed-width, order-preserving Base58 string (see `new_v7_b58_sortable`) into a UUID.
+pub fn from_b58_sortable(s: &str) -> Result<Uuid> {
+	if s.len() != B58_SORTABLE_LEN {
+		return Err(Error::InvalidLength {
+			encoding: "base58-sortable",
+			expected: B58_SORTABLE_LEN,
+			actual: s.len(),
+		});
+	}
+
+	let mut value: u128 = 0;
+	for (index, ch) in s.char_indices() {
+		let digit = b58_digit(ch).ok_or(Error::InvalidCharacter {
+			encoding: "base58-sortable",
+			ch,
+			index,
+		})?;
+		value = value
+			.checked_mul(58)
+			.and_then(|v| v.checked_add(digit as u128))
+			.ok_or(Error::ValueOverflow {
+				encoding: "base58-sortable",
+			})?;
+	}
+
+	Ok(Uuid::from_u128(value))
+}
+
+/// Decodes a fixed-width, order-preserving Base58 string into an epoch millisecond timestamp.
+/// This function is valid only for UUID v7.
+pub fn b58_sortable_to_epoch_ms(s: &str) -> Result<i64> {
+	let uuid = from_b58_sortable(s)?;
+	to_time_epoch_ms(&uuid)
+}
+
 // endregion: --- From String
 
+// region:    --- Support
+
+/// Writes the Base58 encoding into `buf`, returning the written length.
+///
+/// Same output as `bs58` (a `'1'` for each leading zero byte, then the value without leading zeros),
+/// but with `u128` arithmetic by chunks of 10 digits.
+pub(crate) fn write_b58(uuid: &Uuid, buf: &mut [u8; B58_MAX_LEN]) -> usize {
+	let zeros = uuid.as_bytes().iter().take_while(|&&b| b == 0).count();
+
+	// Digits are written from the end, then moved after the leading '1's.
+	let mut digits = [0u8; B58_MAX_LEN];
+	let mut start = B58_MAX_LEN;
+	let mut value = uuid.as_u128();
+	while value > 0 {
+		let mut chunk = (value % B58_POW_10) as u64;
+		value /= B58_POW_10;
+		// Inner chunks are zero-padded to 10 digits, the most significant one is not.
+		for _ in 0..10 {
+			if value == 0 && chunk == 0 {
+				break;
+			}
+			start -= 1;
+			digits[start] = B58_ALPHABET[(chunk % 58) as usize];
+			chunk /= 58;
+		}
+	}
+
+	let digits = &digits[start..];
+	buf[..zeros].fill(B58_ALPHABET[0]);
+	buf[zeros..zeros + digits.len()].copy_from_slice(digits);
+	zeros + digits.len()
+}
+
+/// Writes the UUID as a big-endian 128-bit number in Base58, left-padded to `B58_SORTABLE_LEN`.
+pub(crate) fn write_b58_sortable(uuid: &Uuid, buf: &mut [u8; B58_SORTABLE_LEN]) -> usize {
+	let mut value = uuid.as_u128();
+	for slot in buf.iter_mut().rev() {
+		*slot = B58_ALPHABET[(value % 58) as usize];
+		value /= 58;
+	}
+	B58_SORTABLE_LEN
+}
+
+/// Encodes arbitrary bytes in Base58 (same output as `bs58`), e.g., for payloads longer than a UUID.
+#[cfg(any(feature = "checksum", feature = "signed"))]
+pub(crate) fn encode_b58_bytes(bytes: &[u8]) -> String {
+	let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+
+	// Base58 digits, least significant first (log(256) / log(58) < 1.37).
+	let mut digits: Vec<u8> = Vec::with_capacity((bytes.len() - zeros) * 137 / 100 + 1);
+	for &byte in &bytes[zeros..] {
+		let mut carry = byte as u32;
+		for digit in digits.iter_mut() {
+			carry += (*digit as u32) << 8;
+			*digit = (carry % 58) as u8;
+			carry /= 58;
+		}
+		while carry > 0 {
+			digits.push((carry % 58) as u8);
+			carry /= 58;
+		}
+	}
+
+	let mut encoded = String::with_capacity(zeros + digits.len());
+	encoded.extend(core::iter::repeat_n(B58_ALPHABET[0] as char, zeros));
+	encoded.extend(digits.iter().rev().map(|&digit| B58_ALPHABET[digit as usize] as char));
+	encoded
+}
+
+/// Decodes a Base58 string of arbitrary bytes into `out`, returning the decoded bytes.
+///
+/// Returns `Error::ValueOverflow` if the decoded bytes do not fit in `out`.
+#[cfg(any(feature = "checksum", feature = "signed"))]
+pub(crate) fn decode_b58_bytes<'a>(s: &str, out: &'a mut [u8]) -> Result<&'a [u8]> {
+	let overflow = || Error::ValueOverflow { encoding: "base58" };
+
+	// Bytes, least significant first.
+	let mut len = 0;
+	for (index, ch) in s.char_indices() {
+		let digit = b58_digit(ch).ok_or(Error::InvalidCharacter {
+			encoding: "base58",
+			ch,
+			index,
+		})?;
+		let mut carry = digit as u32;
+		for byte in out[..len].iter_mut() {
+			carry += *byte as u32 * 58;
+			*byte = carry as u8;
+			carry >>= 8;
+		}
+		while carry > 0 {
+			*out.get_mut(len).ok_or_else(overflow)? = carry as u8;
+			len += 1;
+			carry >>= 8;
+		}
+	}
+
+	// Each leading '1' is a leading zero byte.
+	let zeros = s.bytes().take_while(|&b| b == B58_ALPHABET[0]).count();
+	out.get_mut(len..len + zeros).ok_or_else(overflow)?.fill(0);
+	len += zeros;
+
+	out[..len].reverse();
+	Ok(&out[..len])
+}
+
+pub(crate) fn b58_digit(ch: char) -> Option<u8> {
+	let digit = *B58_DIGITS.get(ch as usize)?;
+	(digit != 0xFF).then_some(digit)
+}
+
+// endregion: --- Support
+
 // region:    --- Tests
 
 #[cfg(test)]
@@ -110,6 +366,22 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_extra_base58_new_v5_b58_deterministic() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_ns = crate::NAMESPACE_DNS;
+
+		// -- Exec
+		let b58_v5 = new_v5_b58(&fx_ns, "www.example.com");
+		let b58_v3 = new_v3_b58(&fx_ns, "www.example.com");
+
+		// -- Check
+		assert_eq!(b58_v5, new_v5_b58(&fx_ns, "www.example.com"));
+		assert_eq!(from_b58(&b58_v5)?.get_version(), Some(Version::Sha1));
+		assert_eq!(from_b58(&b58_v3)?.get_version(), Some(Version::Md5));
+		Ok(())
+	}
+
 	// region:    --- Tests for from_... functions
 
 	#[test]
@@ -134,19 +406,42 @@ mod tests {
 	#[test]
 	fn test_extra_base58_from_b58_err_invalid_char() -> Result<()> {
 		// -- Setup & Fixtures
-		let invalid_b58_string = "ThisIsInvalid0"; // '0' is an invalid Base58 character
+		let invalid_b58_string = "ThisIsInvalid0"; // 'I' (index 4), 'l' and '0' are invalid Base58 characters
+
+		// -- Exec
+		let decoded_uuid_res = from_b58(invalid_b58_string);
+
+		// -- Check
+		match decoded_uuid_res {
+			Err(Error::InvalidCharacter { encoding, ch, index }) => {
+				assert_eq!(encoding, "base58");
+				assert_eq!(ch, 'I', "'I' is the first invalid Base58 character");
+				assert_eq!(index, 4);
+			}
+			other => panic!("Expected InvalidCharacter, got {other:?}"),
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base58_from_b58_err_non_ascii() -> Result<()> {
+		// -- Setup & Fixtures
+		let invalid_b58_string = "abcé";
 
 		// -- Exec
 		let decoded_uuid_res = from_b58(invalid_b58_string);
 
 		// -- Check
-		assert!(decoded_uuid_res.is_err(), "Decoding should fail for invalid characters");
-		let err_msg = decoded_uuid_res.err().unwrap().to_string();
-		// bs58::decode::Error::InvalidCharacter Display is "provided string contained invalid character '{character:?}' at byte {index:?}"
-		// This gets wrapped in Error::Custom(String), so the string will be like Custom("...")
 		assert!(
-			err_msg.contains("provided string contained invalid character"),
-			"Error message should indicate invalid Base58 character. Got: {err_msg}",
+			matches!(
+				decoded_uuid_res,
+				Err(Error::InvalidCharacter {
+					encoding: "base58",
+					ch: 'é',
+					index: 3
+				})
+			),
+			"Expected InvalidCharacter, got {decoded_uuid_res:?}"
 		);
 		Ok(())
 	}
@@ -173,8 +468,54 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_extra_base58_from_b58_err_too_long() -> Result<()> {
+		// -- Setup & Fixtures
+		let long_b58_string = "1".repeat(40); // decodes to 40 zero bytes, more than the stack buffer
+
+		// -- Exec
+		let decoded_uuid_res = from_b58(&long_b58_string);
+
+		// -- Check
+		assert!(
+			matches!(
+				decoded_uuid_res,
+				Err(Error::InvalidLength {
+					encoding: "base58",
+					expected: 22,
+					actual: 40
+				})
+			),
+			"Expected InvalidLength, got {decoded_uuid_res:?}"
+		);
+		Ok(())
+	}
+
 	// endregion: --- Tests for from_... functions
 
+	#[test]
+	#[cfg(any(feature = "checksum", feature = "signed"))]
+	fn test_extra_base58_bytes_same_as_bs58() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_payloads: [&[u8]; 5] = [&[], &[0], &[0, 0, 1, 255], &[255; 49], b"key-id | uuid | tag"];
+
+		for payload in fx_payloads {
+			// -- Exec
+			let encoded = encode_b58_bytes(payload);
+			let mut buf = [0u8; 64];
+			let decoded = decode_b58_bytes(&encoded, &mut buf)?;
+
+			// -- Check
+			assert_eq!(encoded, bs58::encode(payload).into_string());
+			assert_eq!(decoded, payload);
+		}
+
+		let mut small_buf = [0u8; 48];
+		let res = decode_b58_bytes(&bs58::encode([255; 49]).into_string(), &mut small_buf);
+		assert!(matches!(res, Err(Error::ValueOverflow { encoding: "base58" })));
+		Ok(())
+	}
+
 	#[test]
 	fn test_extra_base58_b58_to_epoch_ms_ok() -> Result<()> {
 		// -- Setup & Fixtures
@@ -209,6 +550,247 @@ mod tests {
 		}
 		Ok(())
 	}
+
+	#[test]
+	fn test_extra_base58_v7_from_epoch_ms_b58_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_epoch_ms = 1_262_304_000_000;
+
+		// -- Exec
+		let b58_string = v7_from_epoch_ms_b58(fx_epoch_ms)?;
+
+		// -- Check
+		assert_eq!(b58_to_epoch_ms(&b58_string)?, fx_epoch_ms);
+		Ok(())
+	}
+
+	// region:    --- Tests for sortable
+
+	#[test]
+	fn test_extra_base58_new_v7_b58_sortable_simple() -> Result<()> {
+		// -- Setup & Fixtures
+		// (no specific setup needed for this test)
+
+		// -- Exec
+		let b58_sortable = new_v7_b58_sortable();
+
+		// -- Check
+		assert_eq!(b58_sortable.len(), 22, "Sortable Base58 should always be 22 chars");
+		let uuid = from_b58_sortable(&b58_sortable)?;
+		assert_eq!(uuid.get_version(), Some(Version::SortRand));
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base58_from_b58_sortable_ok_edges() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_uuids = [
+			Uuid::nil(),
+			Uuid::max(),
+			Uuid::from_u128(1),
+			Uuid::from_u128(u128::MAX >> 8),
+		];
+
+		for uuid in fx_uuids {
+			// -- Exec
+			let encoded = to_b58_sortable(&uuid);
+			let decoded = from_b58_sortable(&encoded)?;
+
+			// -- Check
+			assert_eq!(encoded.len(), 22);
+			assert_eq!(decoded, uuid);
+		}
+		assert_eq!(to_b58_sortable(&Uuid::nil()), "1111111111111111111111");
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base58_b58_sortable_prop_order_v7() -> Result<()> {
+		// -- Setup & Fixtures
+		let uuids: Vec<Uuid> = (0..10_000).map(|_| new_v7()).collect();
+
+		// -- Exec
+		let encoded: Vec<String> = uuids.iter().map(to_b58_sortable).collect();
+
+		// -- Check
+		for (i, pair) in encoded.windows(2).enumerate() {
+			assert_eq!(
+				uuids[i].cmp(&uuids[i + 1]),
+				pair[0].cmp(&pair[1]),
+				"Order mismatch between {} and {}",
+				uuids[i],
+				uuids[i + 1]
+			);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base58_b58_sortable_prop_order_random_pairs() -> Result<()> {
+		// -- Setup & Fixtures
+		// Mix v4 (full random) with shifted values so leading zero bytes are covered.
+		let fx_uuids: Vec<Uuid> = (0..2_000).map(|i| Uuid::from_u128(new_v4().as_u128() >> (i % 128))).collect();
+
+		// -- Exec & Check
+		for pair in fx_uuids.windows(2) {
+			let (a, b) = (pair[0], pair[1]);
+			let (enc_a, enc_b) = (to_b58_sortable(&a), to_b58_sortable(&b));
+			assert_eq!(a.cmp(&b), enc_a.cmp(&enc_b), "Order mismatch between {a} and {b}");
+			assert_eq!(from_b58_sortable(&enc_a)?, a);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base58_from_b58_sortable_err() -> Result<()> {
+		// -- Setup & Fixtures
+		// -- Exec
+		let res_short = from_b58_sortable("111111111111111111111");
+		let res_invalid_char = from_b58_sortable("111111111111111111111O");
+		let res_overflow = from_b58_sortable("zzzzzzzzzzzzzzzzzzzzzz");
+
+		// -- Check
+		assert!(matches!(
+			res_short,
+			Err(Error::InvalidLength {
+				encoding: "base58-sortable",
+				expected: 22,
+				actual: 21
+			})
+		));
+		assert!(matches!(
+			res_invalid_char,
+			Err(Error::InvalidCharacter {
+				encoding: "base58-sortable",
+				ch: 'O',
+				index: 21
+			})
+		));
+		assert!(matches!(
+			res_overflow,
+			Err(Error::ValueOverflow {
+				encoding: "base58-sortable"
+			})
+		));
+		Ok(())
+	}
+
+	// endregion: --- Tests for sortable
+
+	// region:    --- Tests for bs58 compatibility
+
+	/// Nil, max, leading zero bytes (with small and large tails), and trailing zero bytes.
+	fn fx_edge_uuids() -> Vec<Uuid> {
+		let mut uuids = vec![
+			Uuid::nil(),
+			Uuid::max(),
+			Uuid::from_u128(1),
+			Uuid::from_u128(57),
+			Uuid::from_u128(58),
+		];
+		for zeros in 1..16 {
+			let tail_bits = 8 * (16 - zeros);
+			uuids.push(Uuid::from_u128(1u128 << (tail_bits - 1)));
+			uuids.push(Uuid::from_u128(u128::MAX >> (128 - tail_bits)));
+			uuids.push(Uuid::from_u128(u128::MAX << tail_bits));
+		}
+		uuids
+	}
+
+	#[test]
+	fn test_extra_base58_bs58_compat_edge_cases() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_uuids = fx_edge_uuids();
+
+		for uuid in fx_uuids {
+			// -- Exec
+			let encoded = to_b58(&uuid);
+
+			// -- Check
+			let expected = bs58::encode(uuid.as_bytes()).into_string();
+			assert_eq!(encoded, expected, "Encode mismatch for {uuid}");
+			assert_eq!(from_b58(&expected)?, uuid, "Decode mismatch for {uuid}");
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base58_bs58_compat_random() -> Result<()> {
+		// -- Setup & Fixtures
+		let mut rng = crate::SeededRng::new(58);
+
+		for _ in 0..10_000 {
+			let mut bytes = [0u8; 16];
+			crate::RngSource::fill_bytes(&mut rng, &mut bytes);
+			// Also cover the random leading zero bytes.
+			let zeros = (bytes[0] % 4) as usize;
+			bytes[..zeros].fill(0);
+			let uuid = Uuid::from_bytes(bytes);
+
+			// -- Exec
+			let encoded = to_b58(&uuid);
+
+			// -- Check
+			let expected = bs58::encode(uuid.as_bytes()).into_string();
+			assert_eq!(encoded, expected, "Encode mismatch for {uuid}");
+			assert_eq!(from_b58(&expected)?, uuid, "Decode mismatch for {uuid}");
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base58_from_b58_err_overflow() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_overflow = "zzzzzzzzzzzzzzzzzzzzzz"; // 58^22 - 1 > u128::MAX
+		let fx_17_bytes = bs58::encode([0xFFu8; 17]).into_string();
+
+		// -- Exec
+		let res_overflow = from_b58(fx_overflow);
+		let res_17_bytes = from_b58(&fx_17_bytes);
+
+		// -- Check
+		assert!(
+			matches!(res_overflow, Err(Error::ValueOverflow { encoding: "base58" })),
+			"Expected ValueOverflow, got {res_overflow:?}"
+		);
+		assert!(
+			matches!(
+				res_17_bytes,
+				Err(Error::InvalidLength {
+					encoding: "base58",
+					expected: 22,
+					actual: 24
+				})
+			),
+			"Expected InvalidLength, got {res_17_bytes:?}"
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base58_from_b58_err_leading_zeros_len() -> Result<()> {
+		// -- Setup & Fixtures
+		// 17 leading zero bytes, decodes to 17 bytes with `bs58`.
+		let fx_ones = "1".repeat(17);
+
+		// -- Exec
+		let res = from_b58(&fx_ones);
+
+		// -- Check
+		assert!(
+			matches!(
+				res,
+				Err(Error::FailToDecode16U8 {
+					context: "base58",
+					actual_length: 17
+				})
+			),
+			"Expected FailToDecode16U8, got {res:?}"
+		);
+		Ok(())
+	}
+
+	// endregion: --- Tests for bs58 compatibility
 }
 
 // endregion: --- Tests
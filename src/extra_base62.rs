@@ -0,0 +1,282 @@
+use crate::extra_uuid::{new_v3, new_v4, new_v5, new_v7, to_time_epoch_ms};
+use crate::{EncodedB62, Error, Result};
+use uuid::Uuid;
+
+/// Base62 alphabet (`[0-9A-Za-z]`), which is in ASCII order.
+const B62_ALPHABET: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Number of Base62 characters needed for 128 bits (62^22 > 2^128).
+pub(crate) const B62_UUID_LEN: usize = 22;
+
+// region:    --- v4
+
+/// Generates a new UUID version 4 and encodes it using Base62 (`[0-9A-Za-z]` only).
+pub fn new_v4_b62() -> String {
+	let uuid = new_v4();
+	to_b62(&uuid)
+}
+
+// endregion: --- v4
+
+// region:    --- v7
+
+/// Generates a new UUID version 7 and encodes it using Base62 (`[0-9A-Za-z]` only).
+///
+/// The output is always 22 characters (left-padded with `'0'`).
+pub fn new_v7_b62() -> String {
+	let uuid = new_v7();
+	to_b62(&uuid)
+}
+
+// endregion: --- v7
+
+// region:    --- v5 & v3
+
+/// Generates a UUID version 5 (SHA-1) for `name` within `namespace` and encodes it using Base62.
+pub fn new_v5_b62(namespace: &Uuid, name: impl AsRef<[u8]>) -> String {
+	let uuid = new_v5(namespace, name);
+	to_b62(&uuid)
+}
+
+/// Generates a UUID version 3 (MD5) for `name` within `namespace` and encodes it using Base62.
+pub fn new_v3_b62(namespace: &Uuid, name: impl AsRef<[u8]>) -> String {
+	let uuid = new_v3(namespace, name);
+	to_b62(&uuid)
+}
+
+// endregion: --- v5 & v3
+
+// region:    --- To String
+
+/// Encodes an existing UUID using Base62.
+///
+/// See `EncodedB62` or `encode_b62_into` to encode without allocating.
+pub fn to_b62(uuid: &Uuid) -> String {
+	EncodedB62::new(uuid).to_string()
+}
+
+// endregion: --- To String
+
+// region:    --- From String
+
+/// Decodes a 22-character Base62 encoded string into a UUID.
+pub fn from_b62(s: &str) -> Result<Uuid> {
+	if s.len() != B62_UUID_LEN {
+		return Err(Error::InvalidLength {
+			encoding: "base62",
+			expected: B62_UUID_LEN,
+			actual: s.len(),
+		});
+	}
+
+	let mut value: u128 = 0;
+	for (index, ch) in s.char_indices() {
+		let digit = b62_digit(ch).ok_or(Error::InvalidCharacter {
+			encoding: "base62",
+			ch,
+			index,
+		})?;
+		value = value
+			.checked_mul(62)
+			.and_then(|v| v.checked_add(digit as u128))
+			.ok_or(Error::ValueOverflow { encoding: "base62" })?;
+	}
+
+	Ok(Uuid::from_u128(value))
+}
+
+/// Decodes a Base62 encoded string into an epoch millisecond timestamp.
+/// This function is valid only for UUID v7.
+pub fn b62_to_epoch_ms(s: &str) -> Result<i64> {
+	let uuid = from_b62(s)?;
+	to_time_epoch_ms(&uuid)
+}
+
+// endregion: --- From String
+
+// region:    --- Support
+
+/// Writes the Base62 encoding into `buf`, returning the written length.
+pub(crate) fn write_b62(uuid: &Uuid, buf: &mut [u8; B62_UUID_LEN]) -> usize {
+	let mut value = uuid.as_u128();
+	for slot in buf.iter_mut().rev() {
+		*slot = B62_ALPHABET[(value % 62) as usize];
+		value /= 62;
+	}
+	B62_UUID_LEN
+}
+
+fn b62_digit(ch: char) -> Option<u8> {
+	let digit = match ch {
+		'0'..='9' => ch as u8 - b'0',
+		'A'..='Z' => ch as u8 - b'A' + 10,
+		'a'..='z' => ch as u8 - b'a' + 36,
+		_ => return None,
+	};
+	Some(digit)
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use uuid::{Uuid, Version};
+
+	#[test]
+	fn test_extra_base62_new_v4_b62_simple() -> Result<()> {
+		// -- Setup & Fixtures
+		// (no specific setup needed for this test)
+
+		// -- Exec
+		let b62_uuid = new_v4_b62();
+
+		// -- Check
+		assert_eq!(b62_uuid.len(), 22, "Base62 of UUID should be 22 chars");
+		assert!(
+			b62_uuid.chars().all(|c| c.is_ascii_alphanumeric()),
+			"Base62 should only contain [0-9A-Za-z]. Got: {b62_uuid}"
+		);
+		let uuid = from_b62(&b62_uuid)?;
+		assert_eq!(uuid.get_version(), Some(Version::Random));
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base62_new_v7_b62_simple() -> Result<()> {
+		// -- Setup & Fixtures
+		// (no specific setup needed for this test)
+
+		// -- Exec
+		let b62_uuid = new_v7_b62();
+
+		// -- Check
+		assert_eq!(b62_uuid.len(), 22, "Base62 of V7 UUID should be 22 chars");
+		let uuid = from_b62(&b62_uuid)?;
+		assert_eq!(uuid.get_version(), Some(Version::SortRand));
+		Ok(())
+	}
+
+	// region:    --- Tests for from_... functions
+
+	#[test]
+	fn test_extra_base62_from_b62_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_uuids = [new_v4(), Uuid::nil(), Uuid::max()];
+
+		for original_uuid in fx_uuids {
+			// -- Exec
+			let b62_string = to_b62(&original_uuid);
+			let decoded_uuid = from_b62(&b62_string)?;
+
+			// -- Check
+			assert_eq!(b62_string.len(), 22);
+			assert_eq!(decoded_uuid, original_uuid, "Decoded UUID should match original");
+		}
+		assert_eq!(to_b62(&Uuid::nil()), "0000000000000000000000");
+		assert_eq!(to_b62(&Uuid::max()), "7n42DGM5Tflk9n8mt7Fhc7");
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base62_from_b62_err_invalid_char() -> Result<()> {
+		// -- Setup & Fixtures
+		let invalid_b62_string = "0000000000-00000000000"; // '-' at index 10
+
+		// -- Exec
+		let res = from_b62(invalid_b62_string);
+
+		// -- Check
+		match res {
+			Err(Error::InvalidCharacter { encoding, ch, index }) => {
+				assert_eq!(encoding, "base62");
+				assert_eq!(ch, '-');
+				assert_eq!(index, 10);
+			}
+			other => panic!("Expected InvalidCharacter, got {other:?}"),
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base62_from_b62_err_wrong_len() -> Result<()> {
+		// -- Setup & Fixtures
+		let short_b62_string = "short";
+
+		// -- Exec
+		let res = from_b62(short_b62_string);
+
+		// -- Check
+		match res {
+			Err(Error::InvalidLength {
+				encoding,
+				expected,
+				actual,
+			}) => {
+				assert_eq!(encoding, "base62");
+				assert_eq!(expected, 22);
+				assert_eq!(actual, 5);
+			}
+			other => panic!("Expected InvalidLength, got {other:?}"),
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base62_from_b62_err_overflow() -> Result<()> {
+		// -- Setup & Fixtures
+		let overflow_b62_string = "zzzzzzzzzzzzzzzzzzzzzz";
+
+		// -- Exec
+		let res = from_b62(overflow_b62_string);
+
+		// -- Check
+		assert!(
+			matches!(res, Err(Error::ValueOverflow { encoding: "base62" })),
+			"Expected ValueOverflow, got {res:?}"
+		);
+		Ok(())
+	}
+
+	// endregion: --- Tests for from_... functions
+
+	#[test]
+	fn test_extra_base62_b62_to_epoch_ms_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let original_uuid = new_v7();
+		let b62_string = to_b62(&original_uuid);
+		let original_ts = to_time_epoch_ms(&original_uuid)?;
+
+		// -- Exec
+		let extracted_ts = b62_to_epoch_ms(&b62_string)?;
+
+		// -- Check
+		assert_eq!(extracted_ts, original_ts);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_base62_b62_to_epoch_ms_err_not_v7() -> Result<()> {
+		// -- Setup & Fixtures
+		let uuid_v4 = new_v4();
+		let b62_string = to_b62(&uuid_v4);
+
+		// -- Exec
+		let result = b62_to_epoch_ms(&b62_string);
+
+		// -- Check
+		match result {
+			Err(Error::FailExtractTimeNoUuidV7(id)) => {
+				assert_eq!(id, uuid_v4);
+			}
+			_ => panic!("Expected FailExtractTimeNoUuidV7 error"),
+		}
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -1,26 +1,32 @@
-use crate::extra_uuid::{new_v4, new_v7, to_time_epoch_ms};
-use crate::{Error, Result, support};
+use crate::extra_uuid::{new_v3, new_v4, new_v5, new_v7, to_time_epoch_ms, v7_from_epoch_ms};
+use crate::{EncodedB64, EncodedB64Url, EncodedB64UrlNoPad, Result, support};
 use base64::{engine::general_purpose, Engine as _};
 use uuid::Uuid;
 
+/// Base64 length of 16 bytes with padding (standard and URL-safe).
+pub(crate) const B64_LEN: usize = 24;
+
+/// Base64 length of 16 bytes without padding.
+pub(crate) const B64_NOPAD_LEN: usize = 22;
+
 // region:    --- v4
 
 /// Generates a new UUID version 4 and encodes it using standard Base64.
 pub fn new_v4_b64() -> String {
 	let uuid = new_v4();
-	general_purpose::STANDARD.encode(uuid.as_bytes())
+	to_b64(&uuid)
 }
 
 /// Generates a new UUID version 4 and encodes it using URL-safe Base64.
 pub fn new_v4_b64url() -> String {
 	let uuid = new_v4();
-	general_purpose::URL_SAFE.encode(uuid.as_bytes())
+	to_b64url(&uuid)
 }
 
 /// Generates a new UUID version 4 and encodes it using URL-safe Base64 without padding.
 pub fn new_v4_b64url_nopad() -> String {
 	let uuid = new_v4();
-	general_purpose::URL_SAFE_NO_PAD.encode(uuid.as_bytes())
+	to_b64url_nopad(&uuid)
 }
 
 // endregion: --- v4
@@ -30,29 +36,115 @@ pub fn new_v4_b64url_nopad() -> String {
 /// Generates a new UUID version 7 and encodes it using standard Base64.
 pub fn new_v7_b64() -> String {
 	let uuid = new_v7();
-	general_purpose::STANDARD.encode(uuid.as_bytes())
+	to_b64(&uuid)
 }
 
 /// Generates a new UUID version 7 and encodes it using URL-safe Base64.
 pub fn new_v7_b64url() -> String {
 	let uuid = new_v7();
-	general_purpose::URL_SAFE.encode(uuid.as_bytes())
+	to_b64url(&uuid)
 }
 
 /// Generates a new UUID version 7 and encodes it using URL-safe Base64 without padding.
 pub fn new_v7_b64url_nopad() -> String {
 	let uuid = new_v7();
-	general_purpose::URL_SAFE_NO_PAD.encode(uuid.as_bytes())
+	to_b64url_nopad(&uuid)
+}
+
+/// Generates a new UUID version 7 for the given epoch milliseconds and encodes it using standard Base64.
+pub fn v7_from_epoch_ms_b64(epoch_ms: i64) -> Result<String> {
+	let uuid = v7_from_epoch_ms(epoch_ms)?;
+	Ok(to_b64(&uuid))
+}
+
+/// Generates a new UUID version 7 for the given epoch milliseconds and encodes it using URL-safe Base64.
+pub fn v7_from_epoch_ms_b64url(epoch_ms: i64) -> Result<String> {
+	let uuid = v7_from_epoch_ms(epoch_ms)?;
+	Ok(to_b64url(&uuid))
+}
+
+/// Generates a new UUID version 7 for the given epoch milliseconds and encodes it using URL-safe Base64 without padding.
+pub fn v7_from_epoch_ms_b64url_nopad(epoch_ms: i64) -> Result<String> {
+	let uuid = v7_from_epoch_ms(epoch_ms)?;
+	Ok(to_b64url_nopad(&uuid))
 }
 
 // endregion: --- v7
 
+// region:    --- v5 & v3
+
+/// Generates a UUID version 5 (SHA-1) for `name` within `namespace` and encodes it using standard Base64.
+pub fn new_v5_b64(namespace: &Uuid, name: impl AsRef<[u8]>) -> String {
+	let uuid = new_v5(namespace, name);
+	to_b64(&uuid)
+}
+
+/// Generates a UUID version 3 (MD5) for `name` within `namespace` and encodes it using standard Base64.
+pub fn new_v3_b64(namespace: &Uuid, name: impl AsRef<[u8]>) -> String {
+	let uuid = new_v3(namespace, name);
+	to_b64(&uuid)
+}
+
+/// Generates a UUID version 5 (SHA-1) for `name` within `namespace` and encodes it using URL-safe Base64.
+pub fn new_v5_b64url(namespace: &Uuid, name: impl AsRef<[u8]>) -> String {
+	let uuid = new_v5(namespace, name);
+	to_b64url(&uuid)
+}
+
+/// Generates a UUID version 3 (MD5) for `name` within `namespace` and encodes it using URL-safe Base64.
+pub fn new_v3_b64url(namespace: &Uuid, name: impl AsRef<[u8]>) -> String {
+	let uuid = new_v3(namespace, name);
+	to_b64url(&uuid)
+}
+
+/// Generates a UUID version 5 (SHA-1) for `name` within `namespace` and encodes it using URL-safe Base64 without padding.
+pub fn new_v5_b64url_nopad(namespace: &Uuid, name: impl AsRef<[u8]>) -> String {
+	let uuid = new_v5(namespace, name);
+	to_b64url_nopad(&uuid)
+}
+
+/// Generates a UUID version 3 (MD5) for `name` within `namespace` and encodes it using URL-safe Base64 without padding.
+pub fn new_v3_b64url_nopad(namespace: &Uuid, name: impl AsRef<[u8]>) -> String {
+	let uuid = new_v3(namespace, name);
+	to_b64url_nopad(&uuid)
+}
+
+// endregion: --- v5 & v3
+
+// region:    --- To String
+
+/// Encodes an existing UUID using standard Base64.
+///
+/// See `EncodedB64` or `encode_b64_into` to encode without allocating.
+pub fn to_b64(uuid: &Uuid) -> String {
+	EncodedB64::new(uuid).to_string()
+}
+
+/// Encodes an existing UUID using URL-safe Base64.
+///
+/// See `EncodedB64Url` or `encode_b64url_into` to encode without allocating.
+pub fn to_b64url(uuid: &Uuid) -> String {
+	EncodedB64Url::new(uuid).to_string()
+}
+
+/// Encodes an existing UUID using URL-safe Base64 without padding.
+///
+/// See `EncodedB64UrlNoPad` or `encode_b64url_nopad_into` to encode without allocating.
+pub fn to_b64url_nopad(uuid: &Uuid) -> String {
+	EncodedB64UrlNoPad::new(uuid).to_string()
+}
+
+// endregion: --- To String
+
 // region:    --- From String
 
 /// Decodes a standard Base64 encoded string into a UUID.
 pub fn from_b64(s: &str) -> Result<Uuid> {
-	let decoded_bytes = general_purpose::STANDARD.decode(s).map_err(Error::custom_from_err)?;
-	support::from_vec_u8(decoded_bytes, "base64")
+	let mut buf = [0u8; support::DECODE_BUF_LEN];
+	let len = general_purpose::STANDARD
+		.decode_slice(s, &mut buf)
+		.map_err(|err| support::from_base64_err(err, s, "base64", B64_LEN))?;
+	support::from_slice_u8(&buf[..len], "base64")
 }
 
 /// Decodes a standard Base64 encoded string into an epoch millisecond timestamp.
@@ -64,8 +156,11 @@ pub fn b64_to_epoch_ms(s: &str) -> Result<i64> {
 
 /// Decodes a URL-safe Base64 encoded string (with padding) into a UUID.
 pub fn from_b64url(s: &str) -> Result<Uuid> {
-	let decoded_bytes = general_purpose::URL_SAFE.decode(s).map_err(Error::custom_from_err)?;
-	support::from_vec_u8(decoded_bytes, "base64url")
+	let mut buf = [0u8; support::DECODE_BUF_LEN];
+	let len = general_purpose::URL_SAFE
+		.decode_slice(s, &mut buf)
+		.map_err(|err| support::from_base64_err(err, s, "base64url", B64_LEN))?;
+	support::from_slice_u8(&buf[..len], "base64url")
 }
 
 /// Decodes a URL-safe Base64 encoded string (with padding) into an epoch millisecond timestamp.
@@ -77,8 +172,11 @@ pub fn b64url_to_epoch_ms(s: &str) -> Result<i64> {
 
 /// Decodes a URL-safe Base64 encoded string (without padding) into a UUID.
 pub fn from_b64url_nopad(s: &str) -> Result<Uuid> {
-	let decoded_bytes = general_purpose::URL_SAFE_NO_PAD.decode(s).map_err(Error::custom_from_err)?;
-	support::from_vec_u8(decoded_bytes, "base64url-nopad")
+	let mut buf = [0u8; support::DECODE_BUF_LEN];
+	let len = general_purpose::URL_SAFE_NO_PAD
+		.decode_slice(s, &mut buf)
+		.map_err(|err| support::from_base64_err(err, s, "base64url-nopad", B64_NOPAD_LEN))?;
+	support::from_slice_u8(&buf[..len], "base64url-nopad")
 }
 
 /// Decodes a URL-safe Base64 encoded string (without padding) into an epoch millisecond timestamp.
@@ -90,6 +188,45 @@ pub fn b64url_nopad_to_epoch_ms(s: &str) -> Result<i64> {
 
 // endregion: --- From String
 
+// region:    --- Support
+
+/// Writes the standard Base64 encoding into `buf`, returning the written length.
+pub(crate) fn write_b64(uuid: &Uuid, buf: &mut [u8; B64_LEN]) -> usize {
+	general_purpose::STANDARD
+		.encode_slice(uuid.as_bytes(), buf)
+		.expect("B64_LEN should fit 16 bytes")
+}
+
+/// Writes the URL-safe Base64 encoding into `buf`, returning the written length.
+pub(crate) fn write_b64url(uuid: &Uuid, buf: &mut [u8; B64_LEN]) -> usize {
+	general_purpose::URL_SAFE
+		.encode_slice(uuid.as_bytes(), buf)
+		.expect("B64_LEN should fit 16 bytes")
+}
+
+/// Writes the URL-safe Base64 encoding without padding into `buf`, returning the written length.
+pub(crate) fn write_b64url_nopad(uuid: &Uuid, buf: &mut [u8; B64_NOPAD_LEN]) -> usize {
+	general_purpose::URL_SAFE_NO_PAD
+		.encode_slice(uuid.as_bytes(), buf)
+		.expect("B64_NOPAD_LEN should fit 16 bytes")
+}
+
+/// Encodes an existing UUID using standard Base64 without padding (multibase `m`).
+pub(crate) fn to_b64_nopad(uuid: &Uuid) -> String {
+	general_purpose::STANDARD_NO_PAD.encode(uuid.as_bytes())
+}
+
+/// Decodes a standard Base64 encoded string (without padding) into a UUID.
+pub(crate) fn from_b64_nopad(s: &str) -> Result<Uuid> {
+	let mut buf = [0u8; support::DECODE_BUF_LEN];
+	let len = general_purpose::STANDARD_NO_PAD
+		.decode_slice(s, &mut buf)
+		.map_err(|err| support::from_base64_err(err, s, "base64-nopad", B64_NOPAD_LEN))?;
+	support::from_slice_u8(&buf[..len], "base64-nopad")
+}
+
+// endregion: --- Support
+
 // region:    --- Tests
 
 #[cfg(test)]
@@ -97,6 +234,7 @@ mod tests {
 	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
 
 	use super::*;
+	use crate::Error;
 	use base64::engine::general_purpose as b64_gp;
 	use uuid::{Uuid, Version};
 
@@ -280,6 +418,24 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_extra_base64_v7_from_epoch_ms_b64_variants_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_epoch_ms = 1_262_304_000_000;
+
+		// -- Exec
+		let b64 = v7_from_epoch_ms_b64(fx_epoch_ms)?;
+		let b64url = v7_from_epoch_ms_b64url(fx_epoch_ms)?;
+		let b64url_nopad = v7_from_epoch_ms_b64url_nopad(fx_epoch_ms)?;
+
+		// -- Check
+		assert_eq!(b64_to_epoch_ms(&b64)?, fx_epoch_ms);
+		assert_eq!(b64url_to_epoch_ms(&b64url)?, fx_epoch_ms);
+		assert_eq!(b64url_nopad_to_epoch_ms(&b64url_nopad)?, fx_epoch_ms);
+		assert!(v7_from_epoch_ms_b64url_nopad(-1).is_err());
+		Ok(())
+	}
+
 	// region:    --- Tests for from_... functions
 
 	#[test]
@@ -310,12 +466,14 @@ mod tests {
 		let decoded_uuid_res = from_b64(invalid_b64_string);
 
 		// -- Check
-		assert!(decoded_uuid_res.is_err(), "Decoding should fail for invalid characters");
-		let err_msg = decoded_uuid_res.err().unwrap().to_string();
-		assert!(
-			err_msg.contains("Invalid symbol"),
-			"Error message should indicate 'Invalid symbol'"
-		);
+		match decoded_uuid_res {
+			Err(Error::InvalidCharacter { encoding, ch, index }) => {
+				assert_eq!(encoding, "base64");
+				assert_eq!(ch, '!');
+				assert_eq!(index, 20);
+			}
+			other => panic!("Expected InvalidCharacter, got {other:?}"),
+		}
 		Ok(())
 	}
 
@@ -420,6 +578,35 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_extra_base64_from_b64_err_padding_and_length() -> Result<()> {
+		// -- Setup & Fixtures
+		let missing_padding = b64_gp::STANDARD_NO_PAD.encode(Uuid::new_v4().as_bytes()); // 22 chars, no '=='
+		let bad_length = "QUJDR"; // 5 symbols, the last quad has a single symbol
+
+		// -- Exec
+		let res_padding = from_b64(&missing_padding);
+		let res_length = from_b64url_nopad(bad_length);
+
+		// -- Check
+		assert!(
+			matches!(res_padding, Err(Error::InvalidPadding { encoding: "base64" })),
+			"Expected InvalidPadding, got {res_padding:?}"
+		);
+		assert!(
+			matches!(
+				res_length,
+				Err(Error::InvalidLength {
+					encoding: "base64url-nopad",
+					expected: 22,
+					actual: 5
+				})
+			),
+			"Expected InvalidLength, got {res_length:?}"
+		);
+		Ok(())
+	}
+
 	#[test]
 	fn test_extra_base64_from_b64url_nopad_err_wrong_len() -> Result<()> {
 		// -- Setup & Fixtures
@@ -0,0 +1,315 @@
+//! Checksummed encodings for human-entered ids, so a typo fails with `Error::ChecksumMismatch`
+//! instead of decoding to another valid UUID.
+
+use crate::extra_base32::{B32_UUID_LEN, CROCKFORD_ALPHABET, crockford_digit, from_b32, to_b32};
+use crate::extra_base58::{B58_ALPHABET, B58_MAX_LEN, b58_digit, from_b58, to_b58};
+#[cfg(feature = "checksum")]
+use crate::extra_base58::{decode_b58_bytes, encode_b58_bytes};
+#[cfg(feature = "checksum")]
+use crate::support;
+use crate::{Error, Result};
+#[cfg(feature = "checksum")]
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// Length of the Base58Check checksum (first bytes of the double SHA-256).
+#[cfg(feature = "checksum")]
+const B58CHECK_CHECKSUM_LEN: usize = 4;
+
+// region:    --- Base58Check
+
+/// Encodes an existing UUID using Base58Check, the 16 bytes followed by a 4-byte double SHA-256 checksum
+/// (as in Bitcoin addresses, without a version byte).
+///
+/// Requires the `checksum` feature.
+#[cfg(feature = "checksum")]
+pub fn to_b58check(uuid: &Uuid) -> String {
+	let mut payload = [0u8; 16 + B58CHECK_CHECKSUM_LEN];
+	payload[..16].copy_from_slice(uuid.as_bytes());
+	payload[16..].copy_from_slice(&b58check_checksum(uuid.as_bytes()));
+	encode_b58_bytes(&payload)
+}
+
+/// Decodes a Base58Check encoded string into a UUID.
+///
+/// Returns `Error::ChecksumMismatch` if the checksum does not match (e.g., a mistyped character),
+/// or `Error::InvalidLength` if the input decodes to fewer bytes than the checksum.
+///
+/// Requires the `checksum` feature.
+#[cfg(feature = "checksum")]
+pub fn from_b58check(s: &str) -> Result<Uuid> {
+	let mut buf = [0u8; support::DECODE_BUF_LEN];
+	let decoded = decode_b58_bytes(s, &mut buf)?;
+
+	let Some(payload_len) = decoded.len().checked_sub(B58CHECK_CHECKSUM_LEN) else {
+		return Err(Error::InvalidLength {
+			encoding: "base58check",
+			expected: 16 + B58CHECK_CHECKSUM_LEN,
+			actual: decoded.len(),
+		});
+	};
+	let (payload, checksum) = decoded.split_at(payload_len);
+	if b58check_checksum(payload) != checksum {
+		return Err(Error::ChecksumMismatch {
+			encoding: "base58check",
+		});
+	}
+
+	support::from_slice_u8(payload, "base58check")
+}
+
+// endregion: --- Base58Check
+
+// region:    --- Check Symbol
+
+/// Encodes an existing UUID using Base58, followed by a Luhn mod 58 check character.
+///
+/// The check character catches any single mistyped character and most swaps of adjacent characters.
+pub fn to_b58_luhn(uuid: &Uuid) -> String {
+	let mut encoded = to_b58(uuid);
+	let digits = encoded.chars().filter_map(b58_digit);
+	let check = luhn_check_digit(digits, 58);
+	encoded.push(B58_ALPHABET[check as usize] as char);
+	encoded
+}
+
+/// Decodes a Base58 string with a trailing Luhn mod 58 check character into a UUID.
+///
+/// Returns `Error::ChecksumMismatch` if the check character does not match.
+pub fn from_b58_luhn(s: &str) -> Result<Uuid> {
+	let body = verify_luhn(s, "base58-luhn", B58_MAX_LEN + 1, 58, b58_digit)?;
+	from_b58(body)
+}
+
+/// Encodes an existing UUID using Crockford Base32, followed by a Luhn mod 32 check character.
+///
+/// The check character catches any single mistyped character and most swaps of adjacent characters.
+pub fn to_b32_luhn(uuid: &Uuid) -> String {
+	let mut encoded = to_b32(uuid);
+	let digits = encoded.chars().filter_map(crockford_digit);
+	let check = luhn_check_digit(digits, 32);
+	encoded.push(CROCKFORD_ALPHABET[check as usize] as char);
+	encoded
+}
+
+/// Decodes a Crockford Base32 string with a trailing Luhn mod 32 check character into a UUID.
+///
+/// As with `from_b32`, decoding is case-insensitive, maps `I`/`L` to `1` and `O` to `0`
+/// (before the check), and ignores `-` separators.
+///
+/// Returns `Error::ChecksumMismatch` if the check character does not match.
+pub fn from_b32_luhn(s: &str) -> Result<Uuid> {
+	let body = verify_luhn(s, "base32-luhn", B32_UUID_LEN + 1, 32, crockford_digit)?;
+	from_b32(body)
+}
+
+// endregion: --- Check Symbol
+
+// region:    --- Support
+
+#[cfg(feature = "checksum")]
+fn b58check_checksum(payload: &[u8]) -> [u8; B58CHECK_CHECKSUM_LEN] {
+	let hash = Sha256::digest(Sha256::digest(payload));
+	let mut checksum = [0u8; B58CHECK_CHECKSUM_LEN];
+	checksum.copy_from_slice(&hash[..B58CHECK_CHECKSUM_LEN]);
+	checksum
+}
+
+/// Returns the Luhn mod N check digit of `digits` (each below `n`).
+fn luhn_check_digit(digits: impl DoubleEndedIterator<Item = u8>, n: u32) -> u8 {
+	let sum: u32 = digits
+		.rev()
+		.enumerate()
+		.map(|(i, digit)| {
+			// Starting from the right, every other digit is doubled (its digits summed in base N).
+			let addend = if i % 2 == 0 { digit as u32 * 2 } else { digit as u32 };
+			addend / n + addend % n
+		})
+		.sum();
+	((n - sum % n) % n) as u8
+}
+
+/// Verifies the trailing Luhn check character of `s`, returning the body (without the check character).
+fn verify_luhn<'a>(
+	s: &'a str,
+	encoding: &'static str,
+	expected_len: usize,
+	n: u32,
+	digit_of: fn(char) -> Option<u8>,
+) -> Result<&'a str> {
+	let s = s.trim_end_matches('-');
+	let Some((check_index, check_ch)) = s.char_indices().next_back() else {
+		return Err(Error::InvalidLength {
+			encoding,
+			expected: expected_len,
+			actual: 0,
+		});
+	};
+	let body = &s[..check_index];
+
+	let mut digits = Vec::with_capacity(body.len());
+	for (index, ch) in body.char_indices().chain([(check_index, check_ch)]) {
+		// Separators are left to the decoder (ignored by `from_b32`, rejected by `from_b58`).
+		if ch == '-' {
+			continue;
+		}
+		let digit = digit_of(ch).ok_or(Error::InvalidCharacter { encoding, ch, index })?;
+		digits.push(digit);
+	}
+
+	let check = digits.pop().unwrap_or_default();
+	if luhn_check_digit(digits.into_iter(), n) != check {
+		return Err(Error::ChecksumMismatch { encoding });
+	}
+
+	Ok(body)
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::{new_v4, new_v7};
+
+	#[test]
+	#[cfg(feature = "checksum")]
+	fn test_extra_checked_b58check_roundtrip_and_bs58() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_uuids = [new_v7(), new_v4(), Uuid::nil(), Uuid::max()];
+
+		for uuid in fx_uuids {
+			// -- Exec
+			let encoded = to_b58check(&uuid);
+
+			// -- Check
+			assert_eq!(from_b58check(&encoded)?, uuid);
+			// Same as `bs58` with its `check` feature, without a version byte.
+			let mut payload = uuid.as_bytes().to_vec();
+			payload.extend_from_slice(&b58check_checksum(uuid.as_bytes()));
+			assert_eq!(encoded, bs58::encode(payload).into_string());
+		}
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "checksum")]
+	fn test_extra_checked_b58check_err_typo() -> Result<()> {
+		// -- Setup & Fixtures
+		let encoded = to_b58check(&new_v7());
+		let fx_typo = with_typo(&encoded, 5, B58_ALPHABET);
+
+		// -- Exec
+		let res = from_b58check(&fx_typo);
+
+		// -- Check
+		assert!(
+			matches!(
+				res,
+				Err(Error::ChecksumMismatch {
+					encoding: "base58check"
+				})
+			),
+			"Got {res:?}"
+		);
+		assert!(matches!(
+			from_b58check("2g"),
+			Err(Error::InvalidLength {
+				encoding: "base58check",
+				expected: 20,
+				actual: 1
+			})
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_checked_luhn_roundtrip() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_uuids = [new_v7(), new_v4(), Uuid::nil(), Uuid::max()];
+
+		for uuid in fx_uuids {
+			// -- Exec
+			let b58 = to_b58_luhn(&uuid);
+			let b32 = to_b32_luhn(&uuid);
+
+			// -- Check
+			assert_eq!(b58.len(), to_b58(&uuid).len() + 1);
+			assert_eq!(b32.len(), 27);
+			assert_eq!(from_b58_luhn(&b58)?, uuid);
+			assert_eq!(from_b32_luhn(&b32)?, uuid);
+			assert_eq!(from_b32_luhn(&b32.to_lowercase())?, uuid);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_checked_luhn_err_every_single_typo() -> Result<()> {
+		// -- Setup & Fixtures
+		let uuid = new_v7();
+		let b58 = to_b58_luhn(&uuid);
+		let b32 = to_b32_luhn(&uuid);
+
+		for index in 0..b32.len() {
+			for (encoded, alphabet) in [(&b58, &B58_ALPHABET[..]), (&b32, &CROCKFORD_ALPHABET[..])] {
+				if index >= encoded.len() {
+					continue;
+				}
+				let fx_typo = with_typo(encoded, index, alphabet);
+
+				// -- Exec
+				let res = if alphabet.len() == 58 {
+					from_b58_luhn(&fx_typo)
+				} else {
+					from_b32_luhn(&fx_typo)
+				};
+
+				// -- Check
+				assert!(
+					matches!(res, Err(Error::ChecksumMismatch { .. })),
+					"Typo at {index} of {encoded} ({fx_typo}) should fail, got {res:?}"
+				);
+			}
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_checked_luhn_err_adjacent_swap() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_b32 = "01H3Q2MVTEF8NSRF8Y5WX4PQ3D";
+		let fx_swapped = "01H3Q2MVTEF8NSRF8Y5XW4PQ3D";
+		let check = luhn_check_digit(fx_b32.chars().filter_map(crockford_digit), 32);
+		let fx_encoded = format!("{fx_swapped}{}", CROCKFORD_ALPHABET[check as usize] as char);
+
+		// -- Exec
+		let res = from_b32_luhn(&fx_encoded);
+
+		// -- Check
+		assert!(matches!(
+			res,
+			Err(Error::ChecksumMismatch {
+				encoding: "base32-luhn"
+			})
+		));
+		Ok(())
+	}
+
+	// region:    --- Support
+
+	/// Replaces the char at `index` with the next char of `alphabet`.
+	fn with_typo(encoded: &str, index: usize, alphabet: &[u8]) -> String {
+		let mut bytes = encoded.as_bytes().to_vec();
+		let position = alphabet.iter().position(|&b| b == bytes[index]).unwrap_or_default();
+		bytes[index] = alphabet[(position + 1) % alphabet.len()];
+		String::from_utf8(bytes).unwrap_or_default()
+	}
+
+	// endregion: --- Support
+}
+
+// endregion: --- Tests
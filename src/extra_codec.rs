@@ -0,0 +1,81 @@
+use crate::{Encoding, Result};
+use uuid::Uuid;
+
+/// Compile-time encoding marker, used by encoding-generic types like `Id<Tag, Enc>`.
+///
+/// Each marker maps to one `Encoding`, and encodes/decodes through it.
+pub trait Codec {
+	const ENCODING: Encoding;
+
+	fn encode(uuid: &Uuid) -> String {
+		Self::ENCODING.encode(uuid)
+	}
+
+	fn decode(s: &str) -> Result<Uuid> {
+		Self::ENCODING.decode(s)
+	}
+}
+
+/// Base58 marker (see `new_v7_b58`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct B58;
+
+/// Fixed-width, order-preserving Base58 marker (see `new_v7_b58_sortable`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct B58Sortable;
+
+/// Base62 marker (see `new_v7_b62`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct B62;
+
+/// Crockford Base32 marker (see `new_v7_b32`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct B32;
+
+/// Standard Base64 marker (see `new_v7_b64`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct B64;
+
+/// URL-safe Base64 marker (see `new_v7_b64url`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct B64Url;
+
+/// URL-safe Base64 without padding marker (see `new_v7_b64url_nopad`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct B64UrlNoPad;
+
+/// Canonical hyphenated hex marker (e.g., `67e55044-10b1-426f-9247-bb680e5fe0c8`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Hyphenated;
+
+impl Codec for B58 {
+	const ENCODING: Encoding = Encoding::B58;
+}
+
+impl Codec for B58Sortable {
+	const ENCODING: Encoding = Encoding::B58Sortable;
+}
+
+impl Codec for B62 {
+	const ENCODING: Encoding = Encoding::B62;
+}
+
+impl Codec for B32 {
+	const ENCODING: Encoding = Encoding::B32;
+}
+
+impl Codec for B64 {
+	const ENCODING: Encoding = Encoding::B64;
+}
+
+impl Codec for B64Url {
+	const ENCODING: Encoding = Encoding::B64Url;
+}
+
+impl Codec for B64UrlNoPad {
+	const ENCODING: Encoding = Encoding::B64UrlNoPad;
+}
+
+impl Codec for Hyphenated {
+	const ENCODING: Encoding = Encoding::Hyphenated;
+}
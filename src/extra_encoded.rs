@@ -0,0 +1,272 @@
+//! Non-allocating encoders, writing into fixed-size stack types or caller-provided buffers.
+
+use crate::extra_base32::{B32_UUID_LEN, write_b32};
+use crate::extra_base58::{B58_MAX_LEN, B58_SORTABLE_LEN, write_b58, write_b58_sortable};
+use crate::extra_base62::{B62_UUID_LEN, write_b62};
+use crate::extra_base64::{B64_LEN, B64_NOPAD_LEN, write_b64, write_b64url, write_b64url_nopad};
+use crate::{Result, support};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use uuid::Uuid;
+
+/// Generates a stack-allocated encoded UUID type, and its `encode_*_into` function.
+macro_rules! impl_encoded {
+	($(#[$meta:meta])* $name:ident, $into_fn:ident, $encoding:literal, $max_len:expr, $write_fn:path) => {
+		$(#[$meta])*
+		#[derive(Clone, Copy)]
+		pub struct $name {
+			buf: [u8; $max_len],
+			len: u8,
+		}
+
+		impl $name {
+			/// Maximum length of the encoded string.
+			pub const MAX_LEN: usize = $max_len;
+
+			pub fn new(uuid: &Uuid) -> Self {
+				let mut buf = [0u8; $max_len];
+				let len = $write_fn(uuid, &mut buf);
+				Self { buf, len: len as u8 }
+			}
+
+			pub fn as_str(&self) -> &str {
+				support::ascii_str(&self.buf[..self.len as usize])
+			}
+		}
+
+		impl From<&Uuid> for $name {
+			fn from(uuid: &Uuid) -> Self {
+				Self::new(uuid)
+			}
+		}
+
+		impl Deref for $name {
+			type Target = str;
+
+			fn deref(&self) -> &str {
+				self.as_str()
+			}
+		}
+
+		impl AsRef<str> for $name {
+			fn as_ref(&self) -> &str {
+				self.as_str()
+			}
+		}
+
+		impl fmt::Display for $name {
+			fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+				f.write_str(self.as_str())
+			}
+		}
+
+		impl fmt::Debug for $name {
+			fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+				fmt::Debug::fmt(self.as_str(), f)
+			}
+		}
+
+		impl PartialEq for $name {
+			fn eq(&self, other: &Self) -> bool {
+				self.as_str() == other.as_str()
+			}
+		}
+
+		impl Eq for $name {}
+
+		impl PartialOrd for $name {
+			fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+				Some(self.cmp(other))
+			}
+		}
+
+		impl Ord for $name {
+			fn cmp(&self, other: &Self) -> Ordering {
+				self.as_str().cmp(other.as_str())
+			}
+		}
+
+		impl Hash for $name {
+			fn hash<H: Hasher>(&self, state: &mut H) {
+				self.as_str().hash(state);
+			}
+		}
+
+		impl PartialEq<str> for $name {
+			fn eq(&self, other: &str) -> bool {
+				self.as_str() == other
+			}
+		}
+
+		impl PartialEq<&str> for $name {
+			fn eq(&self, other: &&str) -> bool {
+				self.as_str() == *other
+			}
+		}
+
+		#[doc = concat!("Encodes the UUID with ", $encoding, " into `out`, returning the written `&str`.")]
+		///
+		/// Returns `Error::BufferTooSmall` if `out` cannot hold the encoded string.
+		pub fn $into_fn<'a>(uuid: &Uuid, out: &'a mut [u8]) -> Result<&'a str> {
+			let encoded = $name::new(uuid);
+			support::write_into(encoded.as_bytes(), out, $encoding)
+		}
+	};
+}
+
+impl_encoded!(
+	/// Base58 encoded UUID on the stack (up to 22 chars, see `new_v7_b58`).
+	EncodedB58,
+	encode_b58_into,
+	"base58",
+	B58_MAX_LEN,
+	write_b58
+);
+
+impl_encoded!(
+	/// Fixed-width, order-preserving Base58 encoded UUID on the stack (22 chars, see `new_v7_b58_sortable`).
+	EncodedB58Sortable,
+	encode_b58_sortable_into,
+	"base58-sortable",
+	B58_SORTABLE_LEN,
+	write_b58_sortable
+);
+
+impl_encoded!(
+	/// Base62 encoded UUID on the stack (22 chars, see `new_v7_b62`).
+	EncodedB62,
+	encode_b62_into,
+	"base62",
+	B62_UUID_LEN,
+	write_b62
+);
+
+impl_encoded!(
+	/// Crockford Base32 encoded UUID on the stack (26 chars, see `new_v7_b32`).
+	EncodedB32,
+	encode_b32_into,
+	"base32",
+	B32_UUID_LEN,
+	write_b32
+);
+
+impl_encoded!(
+	/// Standard Base64 encoded UUID on the stack (24 chars, see `new_v7_b64`).
+	EncodedB64,
+	encode_b64_into,
+	"base64",
+	B64_LEN,
+	write_b64
+);
+
+impl_encoded!(
+	/// URL-safe Base64 encoded UUID on the stack (24 chars, see `new_v7_b64url`).
+	EncodedB64Url,
+	encode_b64url_into,
+	"base64url",
+	B64_LEN,
+	write_b64url
+);
+
+impl_encoded!(
+	/// URL-safe Base64 without padding encoded UUID on the stack (22 chars, see `new_v7_b64url_nopad`).
+	EncodedB64UrlNoPad,
+	encode_b64url_nopad_into,
+	"base64url-nopad",
+	B64_NOPAD_LEN,
+	write_b64url_nopad
+);
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::{Encoding, Error, new_v4, new_v7};
+
+	#[test]
+	fn test_extra_encoded_same_as_string_encoders() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_uuids = [new_v4(), new_v7(), Uuid::nil(), Uuid::max()];
+
+		for uuid in fx_uuids {
+			// -- Exec & Check
+			assert_eq!(EncodedB58::new(&uuid), Encoding::B58.encode(&uuid).as_str());
+			assert_eq!(
+				EncodedB58Sortable::new(&uuid),
+				Encoding::B58Sortable.encode(&uuid).as_str()
+			);
+			assert_eq!(EncodedB62::new(&uuid), Encoding::B62.encode(&uuid).as_str());
+			assert_eq!(EncodedB32::new(&uuid), Encoding::B32.encode(&uuid).as_str());
+			assert_eq!(EncodedB64::new(&uuid), Encoding::B64.encode(&uuid).as_str());
+			assert_eq!(EncodedB64Url::new(&uuid), Encoding::B64Url.encode(&uuid).as_str());
+			assert_eq!(
+				EncodedB64UrlNoPad::new(&uuid),
+				Encoding::B64UrlNoPad.encode(&uuid).as_str()
+			);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_encoded_b58_variable_len() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_nil = Uuid::nil();
+
+		// -- Exec
+		let encoded = EncodedB58::new(&fx_nil);
+
+		// -- Check
+		assert_eq!(encoded.len(), 16, "Each leading zero byte is a '1'");
+		assert_eq!(encoded.to_string(), "1111111111111111");
+		assert_eq!(format!("{encoded:?}"), "\"1111111111111111\"");
+		assert_eq!(crate::from_b58(&encoded)?, fx_nil);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_encoded_encode_into_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let uuid = new_v7();
+		let mut buf = [0u8; 64];
+
+		// -- Exec
+		let b64u = encode_b64url_nopad_into(&uuid, &mut buf)?.to_string();
+		let b32 = encode_b32_into(&uuid, &mut buf)?;
+
+		// -- Check
+		assert_eq!(crate::from_b64url_nopad(&b64u)?, uuid);
+		assert_eq!(crate::from_b32(b32)?, uuid);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_encoded_encode_into_err_buffer_too_small() -> Result<()> {
+		// -- Setup & Fixtures
+		let uuid = new_v7();
+		let mut buf = [0u8; 10];
+
+		// -- Exec
+		let res = encode_b64_into(&uuid, &mut buf);
+
+		// -- Check
+		assert!(
+			matches!(
+				res,
+				Err(Error::BufferTooSmall {
+					encoding: "base64",
+					required: 24,
+					actual: 10
+				})
+			),
+			"Expected BufferTooSmall, got {res:?}"
+		);
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,688 @@
+use crate::extra_base32::to_b32;
+use crate::extra_base58::{to_b58, to_b58_sortable};
+use crate::extra_base62::to_b62;
+use crate::extra_base64::{to_b64, to_b64url, to_b64url_nopad};
+#[cfg(feature = "mnemonic")]
+use crate::extra_pronounceable::to_mnemonic;
+use crate::extra_pronounceable::to_proquint;
+use crate::extra_source::{Clock, OsRng, RngSource, SystemClock};
+use crate::extra_typeid::to_typeid;
+use crate::extra_uuid::{gregorian_timestamp, v7_from_epoch_ms_with_rng};
+use crate::{Error, Result};
+use std::sync::{Mutex, PoisonError};
+use uuid::Uuid;
+
+/// Number of bits after the 48-bit timestamp that are not version or variant (12 `rand_a` + 62 `rand_b`).
+const PAYLOAD_BITS: u32 = 74;
+const PAYLOAD_MAX: u128 = (1 << PAYLOAD_BITS) - 1;
+const RAND_B_BITS: u32 = 62;
+
+/// Max random increment used by `V7Method::MonotonicRandom` (and as fallback by `V7Method::SubMillisecond`).
+const RANDOM_INCREMENT_BITS: u32 = 32;
+
+// region:    --- V7Method
+
+/// Strategy used by `V7Generator` to order UUIDs generated within the same millisecond
+/// (see RFC 9562, Section 6.2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum V7Method {
+	/// Method 1 - Fixed-length dedicated counter of `bits` bits (12 to 42), right after the timestamp.
+	///
+	/// The counter is seeded randomly (with its most significant bit cleared) on each new millisecond.
+	Counter { bits: u8 },
+
+	/// Method 2 - Monotonic random, the 74 random bits are incremented by a random amount
+	/// within the same millisecond.
+	#[default]
+	MonotonicRandom,
+
+	/// Method 3 - The 12 `rand_a` bits hold the sub-millisecond fraction of the timestamp.
+	SubMillisecond,
+}
+
+// endregion: --- V7Method
+
+// region:    --- V7Generator
+
+/// Stateful UUID version 7 generator, guaranteeing strictly increasing UUIDs for this instance.
+///
+/// When the clock goes backward, or the counter/random space of the current millisecond is exhausted,
+/// the generator keeps using (or advances) its last timestamp rather than emitting a smaller UUID.
+///
+/// The clock and random source can be injected (e.g., `FixedClock` and `SeededRng`) for reproducible ids.
+///
+/// Note: The guarantee is per instance (generation takes `&mut self`). `new_v7`, `now_v7`, and the
+/// `new_v7_*` functions share one process-wide generator behind a `Mutex`, so they are monotonic
+/// across threads.
+#[derive(Debug, Clone)]
+pub struct V7Generator<C: Clock = SystemClock, R: RngSource = OsRng> {
+	method: V7Method,
+	clock: C,
+	rng: R,
+	last_ms: u64,
+	/// Last 74-bit payload (`rand_a` followed by `rand_b`).
+	last_payload: u128,
+}
+
+/// Constructors
+impl V7Generator {
+	/// Creates a new generator with the given sub-millisecond ordering method,
+	/// using the system clock and the OS random source.
+	///
+	/// Returns `Error::V7CounterBitsOutOfRange` if `V7Method::Counter` bits are not within 12..=42.
+	pub fn new(method: V7Method) -> Result<Self> {
+		V7Generator::with_clock_and_rng(method, SystemClock, OsRng)
+	}
+}
+
+impl Default for V7Generator {
+	fn default() -> Self {
+		V7Generator::new_unchecked(V7Method::default(), SystemClock, OsRng)
+	}
+}
+
+/// Constructors with custom clock and random source
+impl<C: Clock, R: RngSource> V7Generator<C, R> {
+	/// Creates a new generator with the given method, clock, and random source.
+	///
+	/// Returns `Error::V7CounterBitsOutOfRange` if `V7Method::Counter` bits are not within 12..=42.
+	pub fn with_clock_and_rng(method: V7Method, clock: C, rng: R) -> Result<Self> {
+		if let V7Method::Counter { bits } = method
+			&& !(12..=42).contains(&bits)
+		{
+			return Err(Error::V7CounterBitsOutOfRange { bits });
+		}
+
+		Ok(Self::new_unchecked(method, clock, rng))
+	}
+
+	const fn new_unchecked(method: V7Method, clock: C, rng: R) -> Self {
+		Self {
+			method,
+			clock,
+			rng,
+			last_ms: 0,
+			last_payload: 0,
+		}
+	}
+
+	pub fn method(&self) -> V7Method {
+		self.method
+	}
+}
+
+/// Generation
+impl<C: Clock, R: RngSource> V7Generator<C, R> {
+	/// Returns the next UUID version 7, strictly greater than the previous one from this generator.
+	pub fn next_uuid(&mut self) -> Uuid {
+		let since_epoch = self.clock.now();
+		let now_ms = since_epoch.as_millis() as u64;
+		let sub_ms_nanos = since_epoch.subsec_nanos() % 1_000_000;
+
+		let (ms, payload) = if now_ms > self.last_ms {
+			(now_ms, self.first_payload(sub_ms_nanos))
+		} else {
+			// Same millisecond, or the clock went backward: stay on the last timestamp.
+			match self.next_payload(sub_ms_nanos) {
+				Some(payload) => (self.last_ms, payload),
+				None => (self.last_ms + 1, self.first_payload(0)),
+			}
+		};
+
+		self.last_ms = ms;
+		self.last_payload = payload;
+
+		build_v7(ms, payload)
+	}
+
+	/// Returns the next UUID version 7 encoded using Base58.
+	pub fn next_b58(&mut self) -> String {
+		to_b58(&self.next_uuid())
+	}
+
+	/// Returns the next UUID version 7 encoded using fixed-width, order-preserving Base58.
+	pub fn next_b58_sortable(&mut self) -> String {
+		to_b58_sortable(&self.next_uuid())
+	}
+
+	/// Returns the next UUID version 7 encoded using Base62.
+	pub fn next_b62(&mut self) -> String {
+		to_b62(&self.next_uuid())
+	}
+
+	/// Returns the next UUID version 7 encoded using Crockford Base32.
+	pub fn next_b32(&mut self) -> String {
+		to_b32(&self.next_uuid())
+	}
+
+	/// Returns the next UUID version 7 encoded using standard Base64.
+	pub fn next_b64(&mut self) -> String {
+		to_b64(&self.next_uuid())
+	}
+
+	/// Returns the next UUID version 7 encoded using URL-safe Base64.
+	pub fn next_b64url(&mut self) -> String {
+		to_b64url(&self.next_uuid())
+	}
+
+	/// Returns the next UUID version 7 encoded using URL-safe Base64 without padding.
+	pub fn next_b64url_nopad(&mut self) -> String {
+		to_b64url_nopad(&self.next_uuid())
+	}
+}
+
+/// Payload support
+impl<C: Clock, R: RngSource> V7Generator<C, R> {
+	/// Payload for the first UUID of a millisecond.
+	fn first_payload(&mut self, sub_ms_nanos: u32) -> u128 {
+		match self.method {
+			V7Method::Counter { bits } => {
+				let rest_bits = PAYLOAD_BITS - bits as u32;
+				let counter = random_bits(&mut self.rng, bits as u32 - 1);
+				(counter << rest_bits) | random_bits(&mut self.rng, rest_bits)
+			}
+			V7Method::MonotonicRandom => random_bits(&mut self.rng, PAYLOAD_BITS),
+			V7Method::SubMillisecond => {
+				(sub_ms_fraction(sub_ms_nanos) << RAND_B_BITS) | random_bits(&mut self.rng, RAND_B_BITS)
+			}
+		}
+	}
+
+	/// Payload for a subsequent UUID within the same millisecond, or `None` if exhausted.
+	fn next_payload(&mut self, sub_ms_nanos: u32) -> Option<u128> {
+		let last = self.last_payload;
+		match self.method {
+			V7Method::Counter { bits } => {
+				let rest_bits = PAYLOAD_BITS - bits as u32;
+				let counter = (last >> rest_bits) + 1;
+				if counter >> bits != 0 {
+					return None;
+				}
+				Some((counter << rest_bits) | random_bits(&mut self.rng, rest_bits))
+			}
+			V7Method::MonotonicRandom => add_random_increment(&mut self.rng, last),
+			V7Method::SubMillisecond => {
+				let candidate =
+					(sub_ms_fraction(sub_ms_nanos) << RAND_B_BITS) | random_bits(&mut self.rng, RAND_B_BITS);
+				if candidate > last {
+					Some(candidate)
+				} else {
+					add_random_increment(&mut self.rng, last)
+				}
+			}
+		}
+	}
+}
+
+/// Process-wide generator behind `new_v7` and `now_v7`.
+static SHARED_V7: Mutex<V7Generator> = Mutex::new(V7Generator::new_unchecked(
+	V7Method::MonotonicRandom,
+	SystemClock,
+	OsRng,
+));
+
+/// Returns the next UUID version 7 of the process-wide generator, strictly greater than
+/// the previous one (from any thread).
+pub(crate) fn next_shared_v7() -> Uuid {
+	// `next_uuid` keeps the generator consistent even if a holder panicked, so ignore the poisoning.
+	SHARED_V7.lock().unwrap_or_else(PoisonError::into_inner).next_uuid()
+}
+
+// endregion: --- V7Generator
+
+// region:    --- UuidGenerator
+
+/// Generator counterpart of the crate `new_*` functions (v1, v4, v6, v7, in all encodings, TypeID,
+/// and pronounceable), and of `v7_from_epoch_ms`, with an injectable clock and random source.
+///
+/// With `FixedClock`/`SteppingClock` and `SeededRng`, the generated ids are reproducible,
+/// which is handy for snapshot tests.
+#[derive(Debug, Clone)]
+pub struct UuidGenerator<C: Clock = SystemClock, R: RngSource = OsRng> {
+	v7: V7Generator<C, R>,
+}
+
+/// Constructors
+impl UuidGenerator {
+	/// Creates a new generator using the system clock and the OS random source.
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+impl Default for UuidGenerator {
+	fn default() -> Self {
+		Self {
+			v7: V7Generator::default(),
+		}
+	}
+}
+
+/// Constructors with custom clock and random source
+impl<C: Clock, R: RngSource> UuidGenerator<C, R> {
+	/// Creates a new generator with the given v7 method, clock, and random source.
+	///
+	/// Returns `Error::V7CounterBitsOutOfRange` if `V7Method::Counter` bits are not within 12..=42.
+	pub fn with_clock_and_rng(method: V7Method, clock: C, rng: R) -> Result<Self> {
+		Ok(Self {
+			v7: V7Generator::with_clock_and_rng(method, clock, rng)?,
+		})
+	}
+
+	/// Creates a new generator from an existing `V7Generator` (and its clock, random source, and method).
+	pub fn from_v7_generator(v7: V7Generator<C, R>) -> Self {
+		Self { v7 }
+	}
+}
+
+/// Raw Uuid
+impl<C: Clock, R: RngSource> UuidGenerator<C, R> {
+	/// Generates a new UUID version 4 from this generator random source.
+	pub fn new_v4(&mut self) -> Uuid {
+		let mut bytes = [0u8; 16];
+		self.v7.rng.fill_bytes(&mut bytes);
+		uuid::Builder::from_random_bytes(bytes).into_uuid()
+	}
+
+	/// Generates a new UUID version 7 from this generator clock and random source (monotonic).
+	pub fn new_v7(&mut self) -> Uuid {
+		self.v7.next_uuid()
+	}
+
+	/// Generates a new UUID version 7 for the given epoch milliseconds, with random bits from this generator.
+	///
+	/// Returns `Error::V7EpochMsOutOfRange` if `epoch_ms` is negative or does not fit in 48 bits.
+	pub fn v7_from_epoch_ms(&mut self, epoch_ms: i64) -> Result<Uuid> {
+		v7_from_epoch_ms_with_rng(epoch_ms, &mut self.v7.rng)
+	}
+}
+
+/// Gregorian (v1 & v6)
+impl<C: Clock, R: RngSource> UuidGenerator<C, R> {
+	/// Generates a new UUID version 1 from this generator clock, with a random clock sequence.
+	pub fn new_v1(&mut self, node_id: &[u8; 6]) -> Uuid {
+		let ts = self.gregorian_timestamp();
+		Uuid::new_v1(ts, node_id)
+	}
+
+	/// Generates a new UUID version 6 from this generator clock, with a random clock sequence.
+	pub fn new_v6(&mut self, node_id: &[u8; 6]) -> Uuid {
+		let ts = self.gregorian_timestamp();
+		Uuid::new_v6(ts, node_id)
+	}
+
+	fn gregorian_timestamp(&mut self) -> uuid::Timestamp {
+		let mut clock_seq = [0u8; 2];
+		self.v7.rng.fill_bytes(&mut clock_seq);
+		gregorian_timestamp(self.v7.clock.now(), u16::from_be_bytes(clock_seq))
+	}
+}
+
+/// Encoded v4
+impl<C: Clock, R: RngSource> UuidGenerator<C, R> {
+	/// Generates a new UUID version 4 and encodes it using Base58.
+	pub fn new_v4_b58(&mut self) -> String {
+		to_b58(&self.new_v4())
+	}
+
+	/// Generates a new UUID version 4 and encodes it using Base62 (`[0-9A-Za-z]` only).
+	pub fn new_v4_b62(&mut self) -> String {
+		to_b62(&self.new_v4())
+	}
+
+	/// Generates a new UUID version 4 and encodes it using Crockford Base32.
+	pub fn new_v4_b32(&mut self) -> String {
+		to_b32(&self.new_v4())
+	}
+
+	/// Generates a new UUID version 4 and encodes it using standard Base64.
+	pub fn new_v4_b64(&mut self) -> String {
+		to_b64(&self.new_v4())
+	}
+
+	/// Generates a new UUID version 4 and encodes it using URL-safe Base64.
+	pub fn new_v4_b64url(&mut self) -> String {
+		to_b64url(&self.new_v4())
+	}
+
+	/// Generates a new UUID version 4 and encodes it using URL-safe Base64 without padding.
+	pub fn new_v4_b64url_nopad(&mut self) -> String {
+		to_b64url_nopad(&self.new_v4())
+	}
+}
+
+/// Encoded v7
+impl<C: Clock, R: RngSource> UuidGenerator<C, R> {
+	/// Generates a new UUID version 7 and encodes it using Base58.
+	pub fn new_v7_b58(&mut self) -> String {
+		self.v7.next_b58()
+	}
+
+	/// Generates a new UUID version 7 and encodes it using fixed-width, order-preserving Base58
+	/// (see `new_v7_b58_sortable`).
+	pub fn new_v7_b58_sortable(&mut self) -> String {
+		self.v7.next_b58_sortable()
+	}
+
+	/// Generates a new UUID version 7 and encodes it using Base62 (`[0-9A-Za-z]` only).
+	pub fn new_v7_b62(&mut self) -> String {
+		self.v7.next_b62()
+	}
+
+	/// Generates a new UUID version 7 and encodes it using Crockford Base32.
+	pub fn new_v7_b32(&mut self) -> String {
+		self.v7.next_b32()
+	}
+
+	/// Generates a new UUID version 7 and encodes it using standard Base64.
+	pub fn new_v7_b64(&mut self) -> String {
+		self.v7.next_b64()
+	}
+
+	/// Generates a new UUID version 7 and encodes it using URL-safe Base64.
+	pub fn new_v7_b64url(&mut self) -> String {
+		self.v7.next_b64url()
+	}
+
+	/// Generates a new UUID version 7 and encodes it using URL-safe Base64 without padding.
+	pub fn new_v7_b64url_nopad(&mut self) -> String {
+		self.v7.next_b64url_nopad()
+	}
+
+	/// Generates a new UUID version 7 and formats it as a TypeID with `prefix` (see `new_typeid`).
+	///
+	/// Returns `Error::InvalidPrefix` if `prefix` is not valid.
+	pub fn new_typeid(&mut self, prefix: &str) -> Result<String> {
+		to_typeid(prefix, &self.new_v7())
+	}
+}
+
+/// Pronounceable v4
+impl<C: Clock, R: RngSource> UuidGenerator<C, R> {
+	/// Generates a new UUID version 4 and encodes it as proquints.
+	pub fn new_v4_proquint(&mut self) -> String {
+		to_proquint(&self.new_v4())
+	}
+
+	/// Generates a new UUID version 4 and encodes it as a 12-word BIP39 mnemonic.
+	#[cfg(feature = "mnemonic")]
+	pub fn new_v4_mnemonic(&mut self) -> String {
+		to_mnemonic(&self.new_v4())
+	}
+}
+
+// endregion: --- UuidGenerator
+
+// region:    --- Support
+
+fn build_v7(ms: u64, payload: u128) -> Uuid {
+	let ts = (ms as u128 & 0xFFFF_FFFF_FFFF) << 80;
+	let ver = 0x7 << 76;
+	let rand_a = (payload >> RAND_B_BITS) << 64;
+	let var = 0b10 << 62;
+	let rand_b = payload & ((1 << RAND_B_BITS) - 1);
+	Uuid::from_u128(ts | ver | rand_a | var | rand_b)
+}
+
+/// Sub-millisecond fraction scaled to 12 bits (RFC 9562, Method 3).
+fn sub_ms_fraction(sub_ms_nanos: u32) -> u128 {
+	(sub_ms_nanos as u128 * 4096) / 1_000_000
+}
+
+fn add_random_increment(rng: &mut impl RngSource, last: u128) -> Option<u128> {
+	let increment = random_bits(rng, RANDOM_INCREMENT_BITS) + 1;
+	last.checked_add(increment).filter(|payload| *payload <= PAYLOAD_MAX)
+}
+
+fn random_bits(rng: &mut impl RngSource, bits: u32) -> u128 {
+	if bits == 0 {
+		return 0;
+	}
+	let mut bytes = [0u8; 16];
+	rng.fill_bytes(&mut bytes);
+	u128::from_be_bytes(bytes) >> (128 - bits)
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::extra_source::{FixedClock, SeededRng, SteppingClock};
+	use crate::{from_b58, from_b64url_nopad, from_proquint, parse_typeid, to_any_time_epoch_ms, to_time_epoch_ms};
+	use uuid::Version;
+
+	#[test]
+	fn test_extra_generator_next_uuid_monotonic_all_methods() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_methods = [
+			V7Method::Counter { bits: 12 },
+			V7Method::Counter { bits: 42 },
+			V7Method::MonotonicRandom,
+			V7Method::SubMillisecond,
+		];
+
+		for method in fx_methods {
+			let mut generator = V7Generator::new(method)?;
+
+			// -- Exec
+			let uuids: Vec<Uuid> = (0..20_000).map(|_| generator.next_uuid()).collect();
+
+			// -- Check
+			for pair in uuids.windows(2) {
+				assert!(
+					pair[0] < pair[1],
+					"{method:?} - {} should be less than {}",
+					pair[0],
+					pair[1]
+				);
+			}
+			for uuid in uuids.iter() {
+				assert_eq!(uuid.get_version(), Some(Version::SortRand));
+				assert_eq!(uuid.get_variant(), uuid::Variant::RFC4122);
+			}
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_generator_shared_v7_monotonic_across_threads() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_thread_count = 4;
+
+		// -- Exec
+		let handles: Vec<_> = (0..fx_thread_count)
+			.map(|_| std::thread::spawn(|| (0..10_000).map(|_| crate::new_v7()).collect::<Vec<Uuid>>()))
+			.collect();
+		let mut per_thread = Vec::new();
+		for handle in handles {
+			per_thread.push(handle.join().map_err(|_| "Thread should not panic")?);
+		}
+
+		// -- Check
+		for uuids in per_thread.iter() {
+			assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+		}
+		let mut all: Vec<Uuid> = per_thread.into_iter().flatten().collect();
+		all.sort();
+		all.dedup();
+		assert_eq!(all.len(), fx_thread_count * 10_000, "Should have no duplicates");
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_generator_next_uuid_counter_overflow_advances_ms() -> Result<()> {
+		// -- Setup & Fixtures
+		let mut generator = V7Generator::new(V7Method::Counter { bits: 12 })?;
+		let first = generator.next_uuid();
+		let first_ms = to_time_epoch_ms(&first)?;
+
+		// -- Exec
+		// 12-bit counter seeded below 2048, so 4096 more ids must overflow at least once.
+		let mut last = first;
+		for _ in 0..4096 {
+			let next = generator.next_uuid();
+			assert!(last < next);
+			last = next;
+		}
+
+		// -- Check
+		let last_ms = to_time_epoch_ms(&last)?;
+		assert!(last_ms > first_ms, "Timestamp should have advanced");
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_generator_next_encoded_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let mut generator = V7Generator::default();
+
+		// -- Exec
+		let b58 = generator.next_b58();
+		let b64url_nopad = generator.next_b64url_nopad();
+
+		// -- Check
+		let uuid_1 = from_b58(&b58)?;
+		let uuid_2 = from_b64url_nopad(&b64url_nopad)?;
+		assert!(uuid_1 < uuid_2);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_generator_new_err_counter_bits() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_bits = [0, 11, 43, 74];
+
+		for bits in fx_bits {
+			// -- Exec
+			let res = V7Generator::new(V7Method::Counter { bits });
+
+			// -- Check
+			assert!(
+				matches!(res, Err(Error::V7CounterBitsOutOfRange { bits: b }) if b == bits),
+				"Expected V7CounterBitsOutOfRange for {bits}"
+			);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_generator_next_uuid_fixed_clock_monotonic() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_ms = 1_700_000_000_000;
+		let mut generator = V7Generator::with_clock_and_rng(
+			V7Method::Counter { bits: 12 },
+			FixedClock::from_epoch_ms(fx_ms),
+			SeededRng::new(7),
+		)?;
+
+		// -- Exec
+		let uuids: Vec<Uuid> = (0..5_000).map(|_| generator.next_uuid()).collect();
+
+		// -- Check
+		assert_eq!(to_time_epoch_ms(&uuids[0])?, fx_ms as i64);
+		for pair in uuids.windows(2) {
+			assert!(pair[0] < pair[1]);
+		}
+		// 5000 ids in a frozen millisecond overflow a 12-bit counter, so the timestamp runs ahead.
+		assert!(to_time_epoch_ms(&uuids[4_999])? > fx_ms as i64);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_generator_uuid_generator_reproducible() -> Result<()> {
+		// -- Setup & Fixtures
+		let new_generator = |method| {
+			UuidGenerator::with_clock_and_rng(
+				method,
+				SteppingClock::from_epoch_ms(1_700_000_000_000, 1),
+				SeededRng::new(42),
+			)
+		};
+		let mut generator_a = new_generator(V7Method::default())?;
+		let mut generator_b = new_generator(V7Method::default())?;
+		let mut generator_counter = new_generator(V7Method::Counter { bits: 12 })?;
+
+		// -- Exec
+		let ids_a = [
+			generator_a.new_v4_b58(),
+			generator_a.new_v7_b58(),
+			generator_a.new_v7_b64url_nopad(),
+			generator_a.new_v4_b32(),
+		];
+		let ids_b = [
+			generator_b.new_v4_b58(),
+			generator_b.new_v7_b58(),
+			generator_b.new_v7_b64url_nopad(),
+			generator_b.new_v4_b32(),
+		];
+
+		// -- Check
+		assert_eq!(ids_a, ids_b, "Same clock and seed should produce the same ids");
+		assert_eq!(from_b58(&ids_a[0])?.get_version(), Some(Version::Random));
+		let v7 = from_b58(&ids_a[1])?;
+		assert_eq!(v7.get_version(), Some(Version::SortRand));
+		assert_eq!(to_time_epoch_ms(&v7)?, 1_700_000_000_000);
+		assert_eq!(to_time_epoch_ms(&from_b64url_nopad(&ids_a[2])?)?, 1_700_000_000_001);
+		assert_eq!(generator_counter.new_v7().get_version(), Some(Version::SortRand));
+		assert!(matches!(
+			new_generator(V7Method::Counter { bits: 8 }),
+			Err(Error::V7CounterBitsOutOfRange { bits: 8 })
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_generator_uuid_generator_other_versions_reproducible() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_node_id = [0x01, 0x23, 0x45, 0x67, 0x89, 0xAB];
+		let new_generator = || {
+			UuidGenerator::with_clock_and_rng(
+				V7Method::default(),
+				FixedClock::from_epoch_ms(1_700_000_000_000),
+				SeededRng::new(7),
+			)
+		};
+		let mut generator_a = new_generator()?;
+		let mut generator_b = new_generator()?;
+
+		// -- Exec
+		let generate = |generator: &mut UuidGenerator<FixedClock, SeededRng>| -> Result<_> {
+			Ok((
+				generator.new_v1(&fx_node_id),
+				generator.new_v6(&fx_node_id),
+				generator.v7_from_epoch_ms(1_600_000_000_000)?,
+				generator.new_typeid("user")?,
+				generator.new_v4_proquint(),
+			))
+		};
+		let ids_a = generate(&mut generator_a)?;
+		let ids_b = generate(&mut generator_b)?;
+
+		// -- Check
+		assert_eq!(ids_a, ids_b, "Same clock and seed should produce the same ids");
+		let (v1, v6, v7, typeid, proquint) = ids_a;
+		assert_eq!(v1.get_version(), Some(Version::Mac));
+		assert_eq!(v6.get_version(), Some(Version::SortMac));
+		assert_eq!(to_any_time_epoch_ms(&v1)?, 1_700_000_000_000);
+		assert_eq!(to_any_time_epoch_ms(&v6)?, 1_700_000_000_000);
+		assert_eq!(&v1.as_bytes()[10..], &fx_node_id);
+		assert_eq!(to_time_epoch_ms(&v7)?, 1_600_000_000_000);
+		let (prefix, typeid_uuid) = parse_typeid(&typeid)?;
+		assert_eq!(prefix, "user");
+		assert_eq!(to_time_epoch_ms(&typeid_uuid)?, 1_700_000_000_000);
+		assert_eq!(from_proquint(&proquint)?.get_version(), Some(Version::Random));
+		assert!(matches!(
+			generator_a.v7_from_epoch_ms(-1),
+			Err(Error::V7EpochMsOutOfRange { epoch_ms: -1 })
+		));
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
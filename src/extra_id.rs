@@ -0,0 +1,245 @@
+use crate::extra_codec::{B58, Codec};
+use crate::extra_uuid::{new_v4, new_v7, to_time_epoch_ms};
+use crate::{Encoding, Error, Result};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::str::FromStr;
+use uuid::Uuid;
+
+/// Typed UUID newtype, with `Display`/`FromStr` driven by the `Enc` encoding marker (`B58` by default).
+///
+/// The `Tag` type only exists at compile time to keep ids of different resources apart
+/// (e.g., `Id<User>` vs `Id<Order>`), and the memory layout is the same as `Uuid`.
+///
+/// ```ignore
+/// pub struct User;
+/// pub type UserId = Id<User>;                     // Base58
+/// pub type OrderId = Id<Order, B64UrlNoPad>;      // URL-safe Base64 without padding
+/// ```
+#[repr(transparent)]
+pub struct Id<Tag, Enc = B58> {
+	uuid: Uuid,
+	// `fn() -> _` keeps `Id` Send/Sync/Copy regardless of `Tag` and `Enc`.
+	_marker: PhantomData<fn() -> (Tag, Enc)>,
+}
+
+/// Constructors
+impl<Tag, Enc> Id<Tag, Enc> {
+	pub const fn from_uuid(uuid: Uuid) -> Self {
+		Self {
+			uuid,
+			_marker: PhantomData,
+		}
+	}
+
+	/// Generates a new id from a UUID version 4.
+	pub fn new_v4() -> Self {
+		Self::from_uuid(new_v4())
+	}
+
+	/// Generates a new id from a UUID version 7.
+	pub fn new_v7() -> Self {
+		Self::from_uuid(new_v7())
+	}
+}
+
+/// Getters
+impl<Tag, Enc> Id<Tag, Enc> {
+	pub const fn uuid(&self) -> Uuid {
+		self.uuid
+	}
+
+	pub const fn as_uuid(&self) -> &Uuid {
+		&self.uuid
+	}
+
+	/// Returns the epoch milliseconds of the underlying UUID (see `to_time_epoch_ms`).
+	pub fn epoch_ms(&self) -> Result<i64> {
+		to_time_epoch_ms(&self.uuid)
+	}
+}
+
+// region:    --- Display & FromStr
+
+impl<Tag, Enc: Codec> Id<Tag, Enc> {
+	/// Encodes the id into `out` without allocating (see `Encoding::encode_into`).
+	pub fn encode_into<'a>(&self, out: &'a mut [u8]) -> Result<&'a str> {
+		Enc::ENCODING.encode_into(&self.uuid, out)
+	}
+}
+
+impl<Tag, Enc: Codec> fmt::Display for Id<Tag, Enc> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mut buf = [0u8; Encoding::MAX_LEN];
+		let encoded = self.encode_into(&mut buf).map_err(|_| fmt::Error)?;
+		f.write_str(encoded)
+	}
+}
+
+impl<Tag, Enc: Codec> FromStr for Id<Tag, Enc> {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		Enc::decode(s).map(Self::from_uuid)
+	}
+}
+
+// endregion: --- Display & FromStr
+
+// region:    --- Conversions
+
+impl<Tag, Enc> From<Uuid> for Id<Tag, Enc> {
+	fn from(uuid: Uuid) -> Self {
+		Self::from_uuid(uuid)
+	}
+}
+
+impl<Tag, Enc> From<Id<Tag, Enc>> for Uuid {
+	fn from(id: Id<Tag, Enc>) -> Self {
+		id.uuid
+	}
+}
+
+impl<Tag, Enc> AsRef<Uuid> for Id<Tag, Enc> {
+	fn as_ref(&self) -> &Uuid {
+		&self.uuid
+	}
+}
+
+// endregion: --- Conversions
+
+// region:    --- Std Traits
+
+// Note: Implemented manually so that `Tag` and `Enc` do not need to implement them.
+
+impl<Tag, Enc> fmt::Debug for Id<Tag, Enc> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("Id").field(&self.uuid).finish()
+	}
+}
+
+impl<Tag, Enc> Clone for Id<Tag, Enc> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<Tag, Enc> Copy for Id<Tag, Enc> {}
+
+impl<Tag, Enc> PartialEq for Id<Tag, Enc> {
+	fn eq(&self, other: &Self) -> bool {
+		self.uuid == other.uuid
+	}
+}
+
+impl<Tag, Enc> Eq for Id<Tag, Enc> {}
+
+impl<Tag, Enc> PartialOrd for Id<Tag, Enc> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<Tag, Enc> Ord for Id<Tag, Enc> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.uuid.cmp(&other.uuid)
+	}
+}
+
+impl<Tag, Enc> Hash for Id<Tag, Enc> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.uuid.hash(state);
+	}
+}
+
+// endregion: --- Std Traits
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::extra_codec::{B64UrlNoPad, Hyphenated};
+	use crate::{from_b58, from_b64url_nopad};
+	use std::collections::HashSet;
+
+	struct User;
+	struct Order;
+
+	type UserId = Id<User>;
+	type OrderId = Id<Order, B64UrlNoPad>;
+
+	#[test]
+	fn test_extra_id_display_from_str_roundtrip() -> Result<()> {
+		// -- Setup & Fixtures
+		let user_id = UserId::new_v7();
+		let order_id = OrderId::new_v4();
+
+		// -- Exec
+		let user_str = user_id.to_string();
+		let order_str = order_id.to_string();
+
+		// -- Check
+		assert_eq!(from_b58(&user_str)?, user_id.uuid());
+		assert_eq!(from_b64url_nopad(&order_str)?, order_id.uuid());
+		assert_eq!(user_str.parse::<UserId>()?, user_id);
+		assert_eq!(order_str.parse::<OrderId>()?, order_id);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_id_hyphenated_and_epoch_ms() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_uuid = crate::v7_from_epoch_ms(1_700_000_000_000)?;
+		let id: Id<User, Hyphenated> = Id::from_uuid(fx_uuid);
+
+		// -- Exec
+		let id_str = id.to_string();
+
+		// -- Check
+		assert_eq!(id_str, fx_uuid.hyphenated().to_string());
+		assert_eq!(id.epoch_ms()?, 1_700_000_000_000);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_id_zero_cost_and_traits() -> Result<()> {
+		// -- Setup & Fixtures
+		let id_1 = UserId::new_v7();
+		let id_2 = UserId::new_v7();
+
+		// -- Exec
+		let copied = id_1;
+		let set: HashSet<UserId> = [id_1, id_2, copied].into_iter().collect();
+
+		// -- Check
+		assert_eq!(size_of::<UserId>(), size_of::<Uuid>());
+		assert_eq!(size_of::<Option<OrderId>>(), size_of::<Option<Uuid>>());
+		assert_eq!(set.len(), 2);
+		assert!(id_1 < id_2, "v7 ids should be ordered");
+		assert_eq!(Uuid::from(id_1), id_1.uuid());
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_id_from_str_err() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_invalid = "0OIl";
+
+		// -- Exec
+		let res = fx_invalid.parse::<UserId>();
+
+		// -- Check
+		assert!(
+			matches!(res, Err(Error::InvalidCharacter { encoding: "base58", .. })),
+			"Expected InvalidCharacter, got {res:?}"
+		);
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,270 @@
+//! Multibase (self-describing) encodings, where a one-character prefix names the base
+//! (e.g., `z` for base58btc), as used by IPFS/CID tooling.
+//!
+//! See <https://github.com/multiformats/multibase>.
+
+use crate::extra_base32::B32_UUID_LEN;
+use crate::extra_base58::{from_b58, to_b58};
+use crate::extra_base64::{from_b64, from_b64_nopad, from_b64url, from_b64url_nopad, to_b64_nopad};
+use crate::extra_base64::{to_b64, to_b64url, to_b64url_nopad};
+use crate::{Error, Result};
+use derive_more::Display;
+use uuid::Uuid;
+
+/// RFC 4648 Base32 alphabet (lowercase), not to be confused with the crate's Crockford `B32`.
+const RFC4648_B32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+// region:    --- Multibase
+
+/// Multibase bases supported for UUIDs, with their prefix.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
+#[display("{}", self.name())]
+pub enum Multibase {
+	/// `z`, Bitcoin Base58 (same as `to_b58`).
+	Base58Btc,
+	/// `u`, URL-safe Base64 without padding (same as `to_b64url_nopad`).
+	Base64Url,
+	/// `U`, URL-safe Base64 with padding (same as `to_b64url`).
+	Base64UrlPad,
+	/// `m`, standard Base64 without padding.
+	Base64,
+	/// `M`, standard Base64 with padding (same as `to_b64`).
+	Base64Pad,
+	/// `b`, RFC 4648 Base32, lowercase, without padding.
+	Base32,
+	/// `B`, RFC 4648 Base32, uppercase, without padding.
+	Base32Upper,
+}
+
+impl Multibase {
+	pub const ALL: [Multibase; 7] = [
+		Multibase::Base58Btc,
+		Multibase::Base64Url,
+		Multibase::Base64UrlPad,
+		Multibase::Base64,
+		Multibase::Base64Pad,
+		Multibase::Base32,
+		Multibase::Base32Upper,
+	];
+
+	/// Name of the base in the multibase table (e.g., `"base58btc"`).
+	pub fn name(&self) -> &'static str {
+		match self {
+			Multibase::Base58Btc => "base58btc",
+			Multibase::Base64Url => "base64url",
+			Multibase::Base64UrlPad => "base64urlpad",
+			Multibase::Base64 => "base64",
+			Multibase::Base64Pad => "base64pad",
+			Multibase::Base32 => "base32",
+			Multibase::Base32Upper => "base32upper",
+		}
+	}
+
+	/// Prefix character of the base (e.g., `'z'`).
+	pub fn prefix(&self) -> char {
+		match self {
+			Multibase::Base58Btc => 'z',
+			Multibase::Base64Url => 'u',
+			Multibase::Base64UrlPad => 'U',
+			Multibase::Base64 => 'm',
+			Multibase::Base64Pad => 'M',
+			Multibase::Base32 => 'b',
+			Multibase::Base32Upper => 'B',
+		}
+	}
+
+	/// Returns the base of a prefix character, if supported.
+	pub fn from_prefix(prefix: char) -> Option<Multibase> {
+		Multibase::ALL.into_iter().find(|base| base.prefix() == prefix)
+	}
+}
+
+// endregion: --- Multibase
+
+// region:    --- Encode & Decode
+
+/// Encodes an existing UUID in `base`, prefixed with the base character (e.g., `zCg78pMW36DNZ39wPvomBQ`).
+pub fn to_multibase(uuid: &Uuid, base: Multibase) -> String {
+	let encoded = match base {
+		Multibase::Base58Btc => to_b58(uuid),
+		Multibase::Base64Url => to_b64url_nopad(uuid),
+		Multibase::Base64UrlPad => to_b64url(uuid),
+		Multibase::Base64 => to_b64_nopad(uuid),
+		Multibase::Base64Pad => to_b64(uuid),
+		Multibase::Base32 => to_rfc4648_b32(uuid, false),
+		Multibase::Base32Upper => to_rfc4648_b32(uuid, true),
+	};
+	format!("{}{encoded}", base.prefix())
+}
+
+/// Decodes a multibase string into a UUID, returning the base given by its prefix.
+///
+/// Returns `Error::UnknownMultibasePrefix` if the prefix is not one of `Multibase::ALL`,
+/// or the decode error of the base (e.g., `Error::InvalidCharacter`).
+///
+/// Note: Base32 is case-sensitive, `b` expects lowercase and `B` uppercase.
+pub fn from_multibase(s: &str) -> Result<(Multibase, Uuid)> {
+	let mut chars = s.chars();
+	let prefix = chars.next();
+	let base = prefix
+		.and_then(Multibase::from_prefix)
+		.ok_or(Error::UnknownMultibasePrefix { prefix })?;
+	let encoded = chars.as_str();
+
+	let uuid = match base {
+		Multibase::Base58Btc => from_b58(encoded)?,
+		Multibase::Base64Url => from_b64url_nopad(encoded)?,
+		Multibase::Base64UrlPad => from_b64url(encoded)?,
+		Multibase::Base64 => from_b64_nopad(encoded)?,
+		Multibase::Base64Pad => from_b64(encoded)?,
+		Multibase::Base32 => from_rfc4648_b32(encoded, false)?,
+		Multibase::Base32Upper => from_rfc4648_b32(encoded, true)?,
+	};
+	Ok((base, uuid))
+}
+
+// endregion: --- Encode & Decode
+
+// region:    --- Support
+
+/// Encodes the 128 bits MSB-first in 5-bit groups, the last group padded with 3 zero bits.
+fn to_rfc4648_b32(uuid: &Uuid, upper: bool) -> String {
+	let value = uuid.as_u128();
+	(0..B32_UUID_LEN)
+		.map(|i| {
+			let end = 5 * (i + 1);
+			let digit = if end <= 128 {
+				value >> (128 - end)
+			} else {
+				value << (end - 128)
+			};
+			let ch = RFC4648_B32_ALPHABET[(digit & 0x1F) as usize] as char;
+			if upper { ch.to_ascii_uppercase() } else { ch }
+		})
+		.collect()
+}
+
+fn from_rfc4648_b32(s: &str, upper: bool) -> Result<Uuid> {
+	let encoding = "base32-rfc4648";
+	let char_count = s.chars().count();
+	if char_count != B32_UUID_LEN {
+		return Err(Error::InvalidLength {
+			encoding,
+			expected: B32_UUID_LEN,
+			actual: char_count,
+		});
+	}
+
+	let mut value: u128 = 0;
+	for (i, (index, ch)) in s.char_indices().enumerate() {
+		let digit = RFC4648_B32_ALPHABET
+			.iter()
+			.position(|&a| (if upper { a.to_ascii_uppercase() } else { a }) as char == ch);
+		let digit = digit.ok_or(Error::InvalidCharacter { encoding, ch, index })? as u128;
+
+		if i < B32_UUID_LEN - 1 {
+			value = (value << 5) | digit;
+		} else {
+			// The last char carries 3 bits, its 2 padding bits must be zero (canonical encoding).
+			if digit & 0b11 != 0 {
+				return Err(Error::InvalidCharacter { encoding, ch, index });
+			}
+			value = (value << 3) | (digit >> 2);
+		}
+	}
+	Ok(Uuid::from_u128(value))
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::{new_v4, new_v7};
+
+	#[test]
+	fn test_extra_multibase_known_values() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8")?;
+		// Base32 and Base64 values from Python's `base64` module.
+		let fx_expected = [
+			(Multibase::Base58Btc, format!("z{}", to_b58(&fx_uuid))),
+			(Multibase::Base64Url, "uZ-VQRBCxQm-SR7toDl_gyA".to_string()),
+			(Multibase::Base64UrlPad, "UZ-VQRBCxQm-SR7toDl_gyA==".to_string()),
+			(Multibase::Base64, "mZ+VQRBCxQm+SR7toDl/gyA".to_string()),
+			(Multibase::Base64Pad, "MZ+VQRBCxQm+SR7toDl/gyA==".to_string()),
+			(Multibase::Base32, "bm7svaraqwfbg7eshxnua4x7aza".to_string()),
+			(Multibase::Base32Upper, "BM7SVARAQWFBG7ESHXNUA4X7AZA".to_string()),
+		];
+
+		for (base, expected) in fx_expected {
+			// -- Exec
+			let encoded = to_multibase(&fx_uuid, base);
+
+			// -- Check
+			assert_eq!(encoded, expected, "Multibase {base}");
+			assert_eq!(from_multibase(&encoded)?, (base, fx_uuid));
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_multibase_roundtrip() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_uuids = [new_v7(), new_v4(), Uuid::nil(), Uuid::max()];
+
+		for uuid in fx_uuids {
+			for base in Multibase::ALL {
+				// -- Exec
+				let encoded = to_multibase(&uuid, base);
+
+				// -- Check
+				assert!(encoded.starts_with(base.prefix()));
+				assert_eq!(from_multibase(&encoded)?, (base, uuid), "{encoded}");
+			}
+		}
+		assert_eq!(
+			to_multibase(&Uuid::max(), Multibase::Base32),
+			"b77777777777777777777777774"
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_multibase_err() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_b32 = to_multibase(&new_v7(), Multibase::Base32);
+
+		// -- Exec & Check
+		assert!(matches!(
+			from_multibase("f67e5504410b1426f9247bb680e5fe0c8"),
+			Err(Error::UnknownMultibasePrefix { prefix: Some('f') })
+		));
+		assert!(matches!(
+			from_multibase(""),
+			Err(Error::UnknownMultibasePrefix { prefix: None })
+		));
+		assert!(matches!(
+			from_multibase(&format!("B{}", &fx_b32[1..])),
+			Err(Error::InvalidCharacter {
+				encoding: "base32-rfc4648",
+				..
+			})
+		));
+		assert!(matches!(
+			from_multibase("b77777777777777777777777777"),
+			Err(Error::InvalidCharacter { index: 25, .. })
+		));
+		assert!(matches!(
+			from_multibase("z0OIl"),
+			Err(Error::InvalidCharacter { encoding: "base58", .. })
+		));
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,99 @@
+use crate::extra_uuid::{new_v3, new_v5};
+use uuid::Uuid;
+
+/// Builds an unambiguous name from multiple parts for `new_v5`/`new_v3`.
+///
+/// Each part is prefixed with its length (8 bytes, big-endian), so `"ab" + "c"` and `"a" + "bc"`
+/// give different names (and UUIDs), unlike a plain concatenation or a separator that may appear in a part.
+///
+/// ```ignore
+/// let uuid = NameBuilder::new().part(tenant_id).part(email).build_v5(&namespace);
+/// ```
+///
+/// Note: The byte layout is part of the UUID derivation, so it will not change.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NameBuilder {
+	bytes: Vec<u8>,
+}
+
+/// Constructors & Builder
+impl NameBuilder {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Appends a part, prefixed with its length.
+	pub fn part(mut self, part: impl AsRef<[u8]>) -> Self {
+		let part = part.as_ref();
+		self.bytes.extend_from_slice(&(part.len() as u64).to_be_bytes());
+		self.bytes.extend_from_slice(part);
+		self
+	}
+}
+
+/// Getters & Build
+impl NameBuilder {
+	/// The encoded name (length-prefixed parts).
+	pub fn as_bytes(&self) -> &[u8] {
+		&self.bytes
+	}
+
+	/// Generates the UUID version 5 of the name within `namespace`.
+	pub fn build_v5(&self, namespace: &Uuid) -> Uuid {
+		new_v5(namespace, &self.bytes)
+	}
+
+	/// Generates the UUID version 3 of the name within `namespace`.
+	pub fn build_v3(&self, namespace: &Uuid) -> Uuid {
+		new_v3(namespace, &self.bytes)
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::NAMESPACE_URL;
+
+	#[test]
+	fn test_extra_name_builder_no_concat_collision() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_ns = new_v5(&NAMESPACE_URL, "https://example.com/tenants");
+
+		// -- Exec
+		let uuid_ab_c = NameBuilder::new().part("ab").part("c").build_v5(&fx_ns);
+		let uuid_a_bc = NameBuilder::new().part("a").part("bc").build_v5(&fx_ns);
+		let uuid_abc = NameBuilder::new().part("abc").build_v5(&fx_ns);
+		let uuid_abc_empty = NameBuilder::new().part("abc").part("").build_v5(&fx_ns);
+
+		// -- Check
+		assert_ne!(uuid_ab_c, uuid_a_bc);
+		assert_ne!(uuid_ab_c, uuid_abc);
+		assert_ne!(uuid_abc, uuid_abc_empty);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_name_builder_deterministic() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_ns = NAMESPACE_URL;
+		let build = || NameBuilder::new().part("tenant-1").part("jen@example.com");
+
+		// -- Exec
+		let uuid_1 = build().build_v5(&fx_ns);
+		let uuid_2 = build().build_v5(&fx_ns);
+		let uuid_v3 = build().build_v3(&fx_ns);
+
+		// -- Check
+		assert_eq!(uuid_1, uuid_2);
+		assert_eq!(uuid_1.get_version_num(), 5);
+		assert_eq!(uuid_v3.get_version_num(), 3);
+		assert_eq!(&build().as_bytes()[..8], &8u64.to_be_bytes());
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,570 @@
+use crate::extra_base32::to_b32;
+use crate::extra_base58::{to_b58, to_b58_sortable};
+use crate::extra_base62::to_b62;
+use crate::extra_base64::{to_b64, to_b64url, to_b64url_nopad};
+use crate::{Error, Result};
+use crate::{
+	encode_b32_into, encode_b58_into, encode_b58_sortable_into, encode_b62_into, encode_b64_into, encode_b64url_into,
+	encode_b64url_nopad_into,
+};
+use crate::{from_b32, from_b58, from_b58_sortable, from_b62, from_b64, from_b64url, from_b64url_nopad};
+use derive_more::Display;
+use uuid::Uuid;
+
+// region:    --- Encoding
+
+/// Every string format supported by this crate for a UUID.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
+#[display("{}", self.name())]
+pub enum Encoding {
+	/// `67e55044-10b1-426f-9247-bb680e5fe0c8`
+	Hyphenated,
+	/// `67e5504410b1426f9247bb680e5fe0c8`
+	Simple,
+	/// `{67e55044-10b1-426f-9247-bb680e5fe0c8}`
+	Braced,
+	/// `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`
+	Urn,
+	B58,
+	B58Sortable,
+	B62,
+	B32,
+	B64,
+	B64Url,
+	B64UrlNoPad,
+}
+
+impl Encoding {
+	/// All encodings, in the order used by `parse_any` when reporting candidates.
+	pub const ALL: [Encoding; 11] = [
+		Encoding::Hyphenated,
+		Encoding::Simple,
+		Encoding::Braced,
+		Encoding::Urn,
+		Encoding::B58,
+		Encoding::B58Sortable,
+		Encoding::B62,
+		Encoding::B32,
+		Encoding::B64,
+		Encoding::B64Url,
+		Encoding::B64UrlNoPad,
+	];
+
+	/// Encodings tried by `parse_any`, the formats clients usually send.
+	///
+	/// `B58Sortable` and `B62` are left out, since any 22-char alphanumeric string is also valid Base58
+	/// (use `parse_any_of` to accept them).
+	pub const AUTO_DETECT: [Encoding; 8] = [
+		Encoding::Hyphenated,
+		Encoding::Simple,
+		Encoding::Braced,
+		Encoding::Urn,
+		Encoding::B58,
+		Encoding::B64,
+		Encoding::B64Url,
+		Encoding::B64UrlNoPad,
+	];
+
+	/// Largest `max_len()` of all encodings (URN), enough for any `encode_into` buffer.
+	pub const MAX_LEN: usize = uuid::fmt::Urn::LENGTH;
+
+	/// Name of the encoding, matching the `context`/`encoding` of the decode errors (e.g., `"base58"`).
+	pub fn name(&self) -> &'static str {
+		match self {
+			Encoding::Hyphenated => "hyphenated",
+			Encoding::Simple => "simple",
+			Encoding::Braced => "braced",
+			Encoding::Urn => "urn",
+			Encoding::B58 => "base58",
+			Encoding::B58Sortable => "base58-sortable",
+			Encoding::B62 => "base62",
+			Encoding::B32 => "base32",
+			Encoding::B64 => "base64",
+			Encoding::B64Url => "base64url",
+			Encoding::B64UrlNoPad => "base64url-nopad",
+		}
+	}
+
+	/// Encodes the UUID with this encoding.
+	pub fn encode(&self, uuid: &Uuid) -> String {
+		match self {
+			Encoding::Hyphenated => uuid.hyphenated().to_string(),
+			Encoding::Simple => uuid.simple().to_string(),
+			Encoding::Braced => uuid.braced().to_string(),
+			Encoding::Urn => uuid.urn().to_string(),
+			Encoding::B58 => to_b58(uuid),
+			Encoding::B58Sortable => to_b58_sortable(uuid),
+			Encoding::B62 => to_b62(uuid),
+			Encoding::B32 => to_b32(uuid),
+			Encoding::B64 => to_b64(uuid),
+			Encoding::B64Url => to_b64url(uuid),
+			Encoding::B64UrlNoPad => to_b64url_nopad(uuid),
+		}
+	}
+
+	/// Maximum length of the encoded string (Base58 is shorter for small values).
+	pub const fn max_len(&self) -> usize {
+		match self {
+			Encoding::Hyphenated => uuid::fmt::Hyphenated::LENGTH,
+			Encoding::Simple => uuid::fmt::Simple::LENGTH,
+			Encoding::Braced => uuid::fmt::Braced::LENGTH,
+			Encoding::Urn => uuid::fmt::Urn::LENGTH,
+			Encoding::B58 | Encoding::B58Sortable | Encoding::B62 | Encoding::B64UrlNoPad => 22,
+			Encoding::B32 => 26,
+			Encoding::B64 | Encoding::B64Url => 24,
+		}
+	}
+
+	/// Encodes the UUID with this encoding into `out` without allocating, returning the written `&str`.
+	///
+	/// Returns `Error::BufferTooSmall` if `out` cannot hold the encoded string (`Encoding::MAX_LEN` always can).
+	pub fn encode_into<'a>(&self, uuid: &Uuid, out: &'a mut [u8]) -> Result<&'a str> {
+		match self {
+			Encoding::Hyphenated | Encoding::Simple | Encoding::Braced | Encoding::Urn => {
+				let (required, actual) = (self.max_len(), out.len());
+				let out = out.get_mut(..required).ok_or(Error::BufferTooSmall {
+					encoding: self.name(),
+					required,
+					actual,
+				})?;
+				let encoded = match self {
+					Encoding::Hyphenated => uuid.hyphenated().encode_lower(out),
+					Encoding::Simple => uuid.simple().encode_lower(out),
+					Encoding::Braced => uuid.braced().encode_lower(out),
+					_ => uuid.urn().encode_lower(out),
+				};
+				Ok(encoded)
+			}
+			Encoding::B58 => encode_b58_into(uuid, out),
+			Encoding::B58Sortable => encode_b58_sortable_into(uuid, out),
+			Encoding::B62 => encode_b62_into(uuid, out),
+			Encoding::B32 => encode_b32_into(uuid, out),
+			Encoding::B64 => encode_b64_into(uuid, out),
+			Encoding::B64Url => encode_b64url_into(uuid, out),
+			Encoding::B64UrlNoPad => encode_b64url_nopad_into(uuid, out),
+		}
+	}
+
+	/// Decodes the string with this encoding only.
+	///
+	/// For the canonical forms, returns `Error::InvalidLength` or `Error::InvalidCharacter`
+	/// (e.g., a non-hex digit or a misplaced `-`), as the base decoders do.
+	pub fn decode(&self, s: &str) -> Result<Uuid> {
+		match self {
+			Encoding::Hyphenated | Encoding::Simple | Encoding::Braced | Encoding::Urn => decode_canonical(s, *self),
+			Encoding::B58 => from_b58(s),
+			Encoding::B58Sortable => from_b58_sortable(s),
+			Encoding::B62 => from_b62(s),
+			Encoding::B32 => from_b32(s),
+			Encoding::B64 => from_b64(s),
+			Encoding::B64Url => from_b64url(s),
+			Encoding::B64UrlNoPad => from_b64url_nopad(s),
+		}
+	}
+
+	/// Cheap length/alphabet check before attempting a full decode.
+	fn is_plausible(&self, s: &str) -> bool {
+		let len = s.len();
+		match self {
+			Encoding::Hyphenated | Encoding::Simple | Encoding::Braced | Encoding::Urn => {
+				canonical_encoding(s) == Some(*self)
+			}
+			// 16 leading zero bytes give 16 '1's, the largest values give 22 chars.
+			Encoding::B58 => (16..=22).contains(&len),
+			Encoding::B58Sortable | Encoding::B62 => len == 22 && s.bytes().all(|b| b.is_ascii_alphanumeric()),
+			// Crockford Base32 accepts `-` separators.
+			Encoding::B32 => s.bytes().filter(|b| *b != b'-').count() == 26,
+			Encoding::B64 => len == 24 && s.ends_with("==") && !s.contains(['-', '_']),
+			Encoding::B64Url => len == 24 && s.ends_with("==") && !s.contains(['+', '/']),
+			Encoding::B64UrlNoPad => len == 22 && !s.contains(['+', '/', '=']),
+		}
+	}
+}
+
+// endregion: --- Encoding
+
+// region:    --- Parse
+
+/// Parses a UUID from the usual client formats (`Encoding::AUTO_DETECT`), returning the detected encoding.
+///
+/// Accepts the canonical hyphenated, simple, braced, and URN forms, as well as Base58 and the Base64 variants.
+///
+/// A 22-character alphanumeric string can be both Base58 and URL-safe Base64 without padding.
+/// When the candidates decode to different UUIDs, the only one that is an RFC 9562 UUID (known version
+/// and variant) is returned. Otherwise this returns `Error::AmbiguousEncoding` with the candidates
+/// rather than silently picking one, so use `parse_any_of` to narrow the accepted encodings.
+///
+/// Note: When candidates agree on the UUID (e.g., a padded Base64 string without any of `+/-_`),
+/// the first one in `Encoding::AUTO_DETECT` order is returned.
+pub fn parse_any(s: &str) -> Result<(Uuid, Encoding)> {
+	parse_any_of(s, &Encoding::AUTO_DETECT)
+}
+
+/// Same as `parse_any`, but only considers the given encodings (e.g., `&Encoding::ALL`).
+pub fn parse_any_of(s: &str, encodings: &[Encoding]) -> Result<(Uuid, Encoding)> {
+	let plausibles: Vec<Encoding> = encodings.iter().copied().filter(|enc| enc.is_plausible(s)).collect();
+
+	let mut matches: Vec<(Uuid, Encoding)> = Vec::new();
+	let mut last_err: Option<Error> = None;
+	for enc in plausibles.iter() {
+		match enc.decode(s) {
+			Ok(uuid) => matches.push((uuid, *enc)),
+			Err(err) => last_err = Some(err),
+		}
+	}
+
+	let Some(&(first_uuid, first_enc)) = matches.first() else {
+		return match (plausibles.len(), last_err) {
+			// Only one plausible encoding, so its decode error is the most helpful.
+			(1, Some(err)) => Err(err),
+			_ => Err(Error::UnknownEncoding { length: s.len() }),
+		};
+	};
+	if matches.iter().all(|(uuid, _)| *uuid == first_uuid) {
+		return Ok((first_uuid, first_enc));
+	}
+
+	// Candidates disagree, keep the ones that decode to an RFC 9562 UUID.
+	let mut rfc_matches = matches.iter().filter(|(uuid, _)| is_rfc_uuid(uuid));
+	match (rfc_matches.next(), rfc_matches.next()) {
+		(Some(&(uuid, enc)), None) => Ok((uuid, enc)),
+		_ => Err(Error::AmbiguousEncoding {
+			candidates: matches.into_iter().map(|(_, enc)| enc).collect(),
+		}),
+	}
+}
+
+// endregion: --- Parse
+
+// region:    --- Support
+
+/// Returns the canonical encoding by shape (not validating the hex digits).
+fn canonical_encoding(s: &str) -> Option<Encoding> {
+	let is_hyphenated =
+		|h: &str| h.len() == 36 && h.char_indices().all(|(i, c)| matches!(i, 8 | 13 | 18 | 23) == (c == '-'));
+
+	if s.len() == 32 && !s.contains('-') {
+		Some(Encoding::Simple)
+	} else if is_hyphenated(s) {
+		Some(Encoding::Hyphenated)
+	} else if s.len() == 38 && s.starts_with('{') && s.ends_with('}') && is_hyphenated(&s[1..37]) {
+		Some(Encoding::Braced)
+	} else if s.len() == 45 && s.starts_with("urn:uuid:") && is_hyphenated(&s[9..]) {
+		Some(Encoding::Urn)
+	} else {
+		None
+	}
+}
+
+/// Decodes one of the canonical hex forms, checking the exact layout of `encoding` (hex digits are case-insensitive).
+fn decode_canonical(s: &str, encoding: Encoding) -> Result<Uuid> {
+	let (prefix, suffix, hyphens) = match encoding {
+		Encoding::Simple => ("", "", false),
+		Encoding::Braced => ("{", "}", true),
+		Encoding::Urn => ("urn:uuid:", "", true),
+		_ => ("", "", true),
+	};
+	let (name, expected_len) = (encoding.name(), encoding.max_len());
+	let char_count = s.chars().count();
+	if char_count != expected_len {
+		return Err(Error::InvalidLength {
+			encoding: name,
+			expected: expected_len,
+			actual: char_count,
+		});
+	}
+
+	let mut value: u128 = 0;
+	for (pos, (index, ch)) in s.char_indices().enumerate() {
+		let literal = if pos < prefix.len() {
+			Some(prefix.as_bytes()[pos] as char)
+		} else if pos >= expected_len - suffix.len() {
+			suffix.chars().next()
+		} else if hyphens && matches!(pos - prefix.len(), 8 | 13 | 18 | 23) {
+			Some('-')
+		} else {
+			None
+		};
+		match (literal, ch.to_digit(16)) {
+			(Some(literal), _) if ch == literal => {}
+			(None, Some(digit)) => value = (value << 4) | digit as u128,
+			_ => {
+				return Err(Error::InvalidCharacter {
+					encoding: name,
+					ch,
+					index,
+				});
+			}
+		}
+	}
+	Ok(Uuid::from_u128(value))
+}
+
+/// Returns true for the RFC 9562 variant with a known version (1 to 8), or the nil and max UUIDs.
+fn is_rfc_uuid(uuid: &Uuid) -> bool {
+	let known_version = (1..=8).contains(&uuid.get_version_num());
+	uuid.is_nil() || uuid.is_max() || (uuid.get_variant() == uuid::Variant::RFC4122 && known_version)
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+
+	const FX_UUID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+	#[test]
+	fn test_extra_parse_parse_any_canonical_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let uuid = Uuid::parse_str(FX_UUID)?;
+		let fx_inputs = [
+			(FX_UUID.to_string(), Encoding::Hyphenated),
+			(uuid.simple().to_string(), Encoding::Simple),
+			(uuid.braced().to_string(), Encoding::Braced),
+			(uuid.urn().to_string(), Encoding::Urn),
+		];
+
+		for (input, expected_enc) in fx_inputs {
+			// -- Exec
+			let (parsed, enc) = parse_any(&input)?;
+
+			// -- Check
+			assert_eq!(parsed, uuid);
+			assert_eq!(enc, expected_enc, "Wrong encoding for '{input}'");
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_parse_parse_any_base_encodings_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let uuid = Uuid::parse_str(FX_UUID)?;
+		let fx_inputs = [
+			(uuid, to_b58(&uuid), Encoding::B58),
+			(uuid, to_b64(&uuid), Encoding::B64),
+			// All ones encodes to '_' characters, which are specific to URL-safe Base64.
+			(Uuid::max(), to_b64url(&Uuid::max()), Encoding::B64Url),
+		];
+
+		for (uuid, input, expected_enc) in fx_inputs {
+			// -- Exec
+			let (parsed, enc) = parse_any(&input)?;
+
+			// -- Check
+			assert_eq!(parsed, uuid);
+			assert_eq!(enc, expected_enc, "Wrong encoding for '{input}'");
+		}
+		// Crockford Base32 is only detected through `parse_any_of`.
+		assert!(matches!(
+			parse_any(&to_b32(&uuid)),
+			Err(Error::UnknownEncoding { length: 26 })
+		));
+		assert_eq!(parse_any_of(&to_b32(&uuid), &Encoding::ALL)?, (uuid, Encoding::B32));
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_parse_parse_any_of_narrowed_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let uuid = Uuid::parse_str(FX_UUID)?;
+		let fx_encodings = [
+			Encoding::B58,
+			Encoding::B62,
+			Encoding::B64UrlNoPad,
+			Encoding::B58Sortable,
+		];
+
+		for expected_enc in fx_encodings {
+			let input = expected_enc.encode(&uuid);
+
+			// -- Exec
+			let (parsed, enc) = parse_any_of(&input, &[Encoding::Hyphenated, expected_enc])?;
+
+			// -- Check
+			assert_eq!(parsed, uuid);
+			assert_eq!(enc, expected_enc);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_parse_encoding_encode_into_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let uuid = Uuid::parse_str(FX_UUID)?;
+		let mut buf = [0u8; Encoding::MAX_LEN];
+
+		for enc in Encoding::ALL {
+			// -- Exec
+			let encoded = enc.encode_into(&uuid, &mut buf)?;
+
+			// -- Check
+			assert_eq!(encoded, enc.encode(&uuid), "Wrong encode_into for {enc}");
+			assert!(encoded.len() <= enc.max_len(), "Wrong max_len for {enc}");
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_parse_parse_any_real_b58_and_b64url_nopad() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_count = 2_000;
+		let mut ambiguous_count = 0;
+
+		for _ in 0..fx_count {
+			let uuid = crate::new_v4();
+			for (input, expected_enc) in [
+				(to_b58(&uuid), Encoding::B58),
+				(to_b64url_nopad(&uuid), Encoding::B64UrlNoPad),
+			] {
+				// -- Exec
+				let res = parse_any(&input);
+
+				// -- Check
+				match res {
+					Ok((parsed, enc)) => {
+						assert_eq!((parsed, enc), (uuid, expected_enc), "Wrong parse for '{input}'");
+					}
+					// Both decode to a v1-v8 UUID, only Base58 and Base64 URL-safe no-pad can be ambiguous.
+					Err(Error::AmbiguousEncoding { candidates }) => {
+						assert_eq!(candidates, vec![Encoding::B58, Encoding::B64UrlNoPad]);
+						ambiguous_count += 1;
+					}
+					Err(err) => return Err(format!("'{input}' should parse, got {err:?}").into()),
+				}
+			}
+		}
+		// About 1% in theory (about 1 in 8 random values look like a v1-v8 UUID).
+		assert!(
+			ambiguous_count < fx_count * 2 / 20,
+			"Too many ambiguous ids: {ambiguous_count}"
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_parse_parse_any_prefers_rfc_uuid() -> Result<()> {
+		// -- Setup & Fixtures
+		// Base58 of a v4, which also decodes as Base64 URL-safe no-pad (to a non-RFC 9562 value).
+		let fx_input = "62dC2v3S3yHCZi4RnpVWZg";
+		let fx_uuid = Uuid::parse_str("28b7bd76-6a05-46a8-bffb-4bab40cb3e6b")?;
+		assert!(from_b64url_nopad(fx_input).is_ok());
+
+		// -- Exec
+		let (parsed, enc) = parse_any(fx_input)?;
+
+		// -- Check
+		assert_eq!((parsed, enc), (fx_uuid, Encoding::B58));
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_parse_parse_any_err_ambiguous() -> Result<()> {
+		// -- Setup & Fixtures
+		// Base58 of a v4, and Base64 URL-safe no-pad of a v2.
+		let fx_input = "N6x9712uLC6S5GZuhuebsA";
+		// Base62 of a v4, and sortable Base58 of a v7.
+		let fx_input_b62 = "3mQUziCg8V2SnpxCeQZDdm";
+
+		// -- Exec
+		let res = parse_any(fx_input);
+		let res_b62 = parse_any_of(fx_input_b62, &[Encoding::B58Sortable, Encoding::B62]);
+
+		// -- Check
+		match res {
+			Err(Error::AmbiguousEncoding { candidates }) => {
+				assert_eq!(candidates, vec![Encoding::B58, Encoding::B64UrlNoPad]);
+			}
+			other => panic!("Expected AmbiguousEncoding, got {other:?}"),
+		}
+		match res_b62 {
+			Err(Error::AmbiguousEncoding { candidates }) => {
+				assert_eq!(candidates, vec![Encoding::B58Sortable, Encoding::B62]);
+			}
+			other => panic!("Expected AmbiguousEncoding, got {other:?}"),
+		}
+		assert!(parse_any(fx_input_b62).is_ok_and(|(_, enc)| enc == Encoding::B58));
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_parse_parse_any_err_unknown() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_input = "not-a-uuid";
+
+		// -- Exec
+		let res = parse_any(fx_input);
+
+		// -- Check
+		assert!(
+			matches!(res, Err(Error::UnknownEncoding { length: 10 })),
+			"Expected UnknownEncoding, got {res:?}"
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_parse_encoding_decode_canonical_err() -> Result<()> {
+		// -- Setup & Fixtures
+		let uuid = Uuid::parse_str(FX_UUID)?;
+		let fx_upper = FX_UUID.to_uppercase();
+		let fx_braced_no_close = format!("{{{FX_UUID}]");
+
+		// -- Exec & Check
+		assert_eq!(Encoding::Hyphenated.decode(&fx_upper)?, uuid);
+		assert!(matches!(
+			Encoding::Simple.decode(FX_UUID),
+			Err(Error::InvalidLength {
+				encoding: "simple",
+				expected: 32,
+				actual: 36
+			})
+		));
+		assert!(matches!(
+			Encoding::Hyphenated.decode("67e5504410b1-426f-9247-bb680e5fe0c8-"),
+			Err(Error::InvalidCharacter { ch: '1', index: 8, .. })
+		));
+		assert!(matches!(
+			Encoding::Braced.decode(&fx_braced_no_close),
+			Err(Error::InvalidCharacter {
+				encoding: "braced",
+				ch: ']',
+				index: 37
+			})
+		));
+		assert!(matches!(
+			Encoding::Urn.decode(&format!("URN:UUID:{FX_UUID}")),
+			Err(Error::InvalidCharacter { ch: 'U', index: 0, .. })
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_parse_parse_any_err_single_candidate() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_input = "67e55044-10b1-426f-9247-bb680e5fe0cZ"; // hyphenated shape, invalid hex
+
+		// -- Exec
+		let res = parse_any(fx_input);
+
+		// -- Check
+		assert!(
+			matches!(
+				res,
+				Err(Error::InvalidCharacter {
+					encoding: "hyphenated",
+					ch: 'Z',
+					index: 35
+				})
+			),
+			"Expected the hyphenated decode error, got {res:?}"
+		);
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,247 @@
+use crate::extra_codec::{B58, Codec};
+use crate::extra_uuid::{new_v4, new_v7};
+use crate::{Encoding, Error, Result};
+use core::marker::PhantomData;
+use uuid::Uuid;
+
+/// Formatter and parser of prefixed ids over any encoding marker (`B58` by default),
+/// e.g., Stripe-style `ord_<base58>` or `cus_<base64url-nopad>`.
+///
+/// The prefix is ASCII lowercase letters and digits, so the first separator always ends it
+/// (even when the encoding alphabet contains the separator, like `_` for `B64UrlNoPad`).
+///
+/// ```ignore
+/// let orders = Prefixed::<B58>::new("ord")?;
+/// let id = orders.new_v7();                                  // "ord_Cg78pMW36DNZ39wPvomBQ"
+/// let uuid = orders.parse(&id)?;
+/// let uuid = Prefixed::<B58>::parse_expect_prefix("ord", &id)?;
+/// ```
+///
+/// See `TypeId` for the TypeID format.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Prefixed<Enc = B58> {
+	prefix: String,
+	separator: char,
+	_marker: PhantomData<fn() -> Enc>,
+}
+
+/// Constructors
+impl<Enc: Codec> Prefixed<Enc> {
+	pub const DEFAULT_SEPARATOR: char = '_';
+
+	/// Creates a formatter for `prefix`, with the `_` separator.
+	///
+	/// Returns `Error::InvalidPrefix` if `prefix` is empty or not only ASCII lowercase letters and digits.
+	pub fn new(prefix: impl Into<String>) -> Result<Self> {
+		let prefix = prefix.into();
+		validate_prefix(&prefix)?;
+		Ok(Self {
+			prefix,
+			separator: Self::DEFAULT_SEPARATOR,
+			_marker: PhantomData,
+		})
+	}
+
+	/// Sets the separator, which must be an ASCII punctuation char (e.g., `-`, `.`, or `:`).
+	pub fn with_separator(mut self, separator: char) -> Result<Self> {
+		if !separator.is_ascii_punctuation() {
+			return Err(Error::InvalidSeparator { separator });
+		}
+		self.separator = separator;
+		Ok(self)
+	}
+}
+
+/// Getters
+impl<Enc: Codec> Prefixed<Enc> {
+	pub fn prefix(&self) -> &str {
+		&self.prefix
+	}
+
+	pub fn separator(&self) -> char {
+		self.separator
+	}
+
+	pub fn encoding(&self) -> Encoding {
+		Enc::ENCODING
+	}
+}
+
+/// Format
+impl<Enc: Codec> Prefixed<Enc> {
+	/// Formats an existing UUID as `<prefix><separator><encoded uuid>`.
+	pub fn format(&self, uuid: &Uuid) -> String {
+		format!("{}{}{}", self.prefix, self.separator, Enc::encode(uuid))
+	}
+
+	/// Generates a new UUID version 4 and formats it.
+	pub fn new_v4(&self) -> String {
+		self.format(&new_v4())
+	}
+
+	/// Generates a new UUID version 7 and formats it.
+	pub fn new_v7(&self) -> String {
+		self.format(&new_v7())
+	}
+}
+
+/// Parse
+impl<Enc: Codec> Prefixed<Enc> {
+	/// Parses an id with this prefix and separator.
+	///
+	/// Returns `Error::MissingSeparator`, `Error::PrefixMismatch`,
+	/// or the decode error of the encoding (e.g., `Error::InvalidCharacter`).
+	pub fn parse(&self, s: &str) -> Result<Uuid> {
+		let (prefix, uuid) = split_prefixed::<Enc>(s, self.separator)?;
+		check_prefix(prefix, &self.prefix)?;
+		Ok(uuid)
+	}
+
+	/// Parses an id with the `_` separator, checking that its prefix is `expected_prefix`.
+	pub fn parse_expect_prefix(expected_prefix: &str, s: &str) -> Result<Uuid> {
+		let (prefix, uuid) = Self::split(s)?;
+		check_prefix(prefix, expected_prefix)?;
+		Ok(uuid)
+	}
+
+	/// Splits an id with the `_` separator into its (validated) prefix and UUID, whatever the prefix.
+	pub fn split(s: &str) -> Result<(&str, Uuid)> {
+		split_prefixed::<Enc>(s, Self::DEFAULT_SEPARATOR)
+	}
+}
+
+// region:    --- Support
+
+fn validate_prefix(prefix: &str) -> Result<()> {
+	let invalid = |reason: &'static str| Error::InvalidPrefix {
+		prefix: prefix.to_string(),
+		reason,
+	};
+
+	if prefix.is_empty() {
+		return Err(invalid("empty"));
+	}
+	if !prefix.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit()) {
+		return Err(invalid("only lowercase ASCII letters and digits are allowed"));
+	}
+	Ok(())
+}
+
+pub(crate) fn check_prefix(prefix: &str, expected_prefix: &str) -> Result<()> {
+	if prefix != expected_prefix {
+		return Err(Error::PrefixMismatch {
+			expected: expected_prefix.to_string(),
+			actual: prefix.to_string(),
+		});
+	}
+	Ok(())
+}
+
+fn split_prefixed<Enc: Codec>(s: &str, separator: char) -> Result<(&str, Uuid)> {
+	let (prefix, encoded) = s.split_once(separator).ok_or(Error::MissingSeparator { separator })?;
+	validate_prefix(prefix)?;
+	let uuid = Enc::decode(encoded)?;
+	Ok((prefix, uuid))
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::{B64UrlNoPad, Hyphenated, from_b58, from_b64url_nopad};
+
+	#[test]
+	fn test_extra_prefixed_format_parse_roundtrip() -> Result<()> {
+		// -- Setup & Fixtures
+		let orders = Prefixed::<B58>::new("ord")?;
+		let customers = Prefixed::<B64UrlNoPad>::new("cus")?;
+		let fx_uuid = new_v7();
+
+		// -- Exec
+		let ord_id = orders.format(&fx_uuid);
+		let cus_id = customers.format(&fx_uuid);
+
+		// -- Check
+		let (ord_prefix, ord_b58) = ord_id.split_once('_').ok_or("Should have a separator")?;
+		assert_eq!(ord_prefix, "ord");
+		assert_eq!(from_b58(ord_b58)?, fx_uuid);
+		assert_eq!(from_b64url_nopad(&cus_id["cus_".len()..])?, fx_uuid);
+		assert_eq!(orders.parse(&ord_id)?, fx_uuid);
+		assert_eq!(customers.parse(&cus_id)?, fx_uuid);
+		assert_eq!(Prefixed::<B64UrlNoPad>::split(&cus_id)?, ("cus", fx_uuid));
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_prefixed_b64url_nopad_underscore_in_encoded() -> Result<()> {
+		// -- Setup & Fixtures
+		// 0xFF bytes encode to `_` in URL-safe Base64.
+		let fx_uuid = Uuid::max();
+		let customers = Prefixed::<B64UrlNoPad>::new("cus")?;
+
+		// -- Exec
+		let cus_id = customers.format(&fx_uuid);
+
+		// -- Check
+		assert_eq!(cus_id, "cus______________________w");
+		assert_eq!(Prefixed::<B64UrlNoPad>::parse_expect_prefix("cus", &cus_id)?, fx_uuid);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_prefixed_with_separator() -> Result<()> {
+		// -- Setup & Fixtures
+		let invoices = Prefixed::<Hyphenated>::new("inv")?.with_separator(':')?;
+
+		// -- Exec
+		let inv_id = invoices.new_v4();
+
+		// -- Check
+		assert!(inv_id.starts_with("inv:"), "Got: {inv_id}");
+		assert_eq!(invoices.parse(&inv_id)?.get_version_num(), 4);
+		assert!(matches!(
+			Prefixed::<B58>::new("ord")?.with_separator('x'),
+			Err(Error::InvalidSeparator { separator: 'x' })
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_prefixed_parse_err() -> Result<()> {
+		// -- Setup & Fixtures
+		let orders = Prefixed::<B58>::new("ord")?;
+		let fx_cus_id = Prefixed::<B58>::new("cus")?.new_v7();
+
+		// -- Exec
+		let res_mismatch = Prefixed::<B58>::parse_expect_prefix("ord", &fx_cus_id);
+		let res_no_sep = orders.parse("ordCg78pMW36DNZ39wPvomBQ");
+		let res_decode = orders.parse("ord_0OIl");
+		let res_invalid = Prefixed::<B58>::new("Ord");
+
+		// -- Check
+		match res_mismatch {
+			Err(Error::PrefixMismatch { expected, actual }) => {
+				assert_eq!((expected.as_str(), actual.as_str()), ("ord", "cus"));
+			}
+			other => return Err(format!("Expected PrefixMismatch, got {other:?}").into()),
+		}
+		assert!(matches!(res_no_sep, Err(Error::MissingSeparator { separator: '_' })));
+		assert!(matches!(
+			res_decode,
+			Err(Error::InvalidCharacter {
+				encoding: "base58",
+				ch: '0',
+				..
+			})
+		));
+		assert!(matches!(res_invalid, Err(Error::InvalidPrefix { .. })));
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,360 @@
+//! Human-pronounceable encodings, for ids read aloud (e.g., device pairing codes).
+//!
+//! - Proquints (`lusab-babad-...`), 8 five-letter syllable groups of 16 bits each.
+//! - BIP39 mnemonic, 12 words of the 2048-word English list, with a 4-bit checksum (`mnemonic` feature).
+
+use crate::extra_uuid::new_v4;
+#[cfg(feature = "mnemonic")]
+use crate::support_bip39::BIP39_ENGLISH;
+use crate::{Error, Result};
+#[cfg(feature = "mnemonic")]
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// Proquint consonants (4 bits each).
+const PROQUINT_CONSONANTS: &[u8; 16] = b"bdfghjklmnprstvz";
+/// Proquint vowels (2 bits each).
+const PROQUINT_VOWELS: &[u8; 4] = b"aiou";
+const PROQUINT_WORD_COUNT: usize = 8;
+
+/// 128 bits of entropy and a 4-bit checksum, 11 bits per word.
+#[cfg(feature = "mnemonic")]
+const MNEMONIC_WORD_COUNT: usize = 12;
+
+// region:    --- v4
+
+/// Generates a new UUID version 4 and encodes it as proquints.
+///
+/// Note: Use v4 for codes read aloud, a v7 shares its timestamp prefix with the ids of the same moment.
+pub fn new_v4_proquint() -> String {
+	to_proquint(&new_v4())
+}
+
+/// Generates a new UUID version 4 and encodes it as a 12-word BIP39 mnemonic.
+#[cfg(feature = "mnemonic")]
+pub fn new_v4_mnemonic() -> String {
+	to_mnemonic(&new_v4())
+}
+
+// endregion: --- v4
+
+// region:    --- Proquint
+
+/// Encodes an existing UUID as 8 proquints separated by `-` (e.g., `lusab-babad-...`).
+pub fn to_proquint(uuid: &Uuid) -> String {
+	let mut encoded = String::with_capacity(PROQUINT_WORD_COUNT * 6 - 1);
+	for (i, pair) in uuid.as_bytes().chunks_exact(2).enumerate() {
+		if i > 0 {
+			encoded.push('-');
+		}
+		let value = u16::from_be_bytes([pair[0], pair[1]]);
+		// consonant (4) | vowel (2) | consonant (4) | vowel (2) | consonant (4)
+		let consonant = |shift: u16| PROQUINT_CONSONANTS[(value >> shift & 0xF) as usize] as char;
+		let vowel = |shift: u16| PROQUINT_VOWELS[(value >> shift & 0x3) as usize] as char;
+		encoded.extend([consonant(12), vowel(10), consonant(6), vowel(4), consonant(0)]);
+	}
+	encoded
+}
+
+/// Decodes proquints into a UUID.
+///
+/// Decoding is case-insensitive, and the words can be separated by `-` and/or whitespace.
+pub fn from_proquint(s: &str) -> Result<Uuid> {
+	let words = normalized_words(s, |ch| ch == '-' || ch.is_whitespace());
+
+	let mut bytes = [0u8; 16];
+	let mut count = 0;
+	for (index, word) in words.enumerate() {
+		let value = proquint_value(&word).ok_or_else(|| Error::InvalidWord {
+			encoding: "proquint",
+			word: word.clone(),
+			index,
+		})?;
+		if let Some(pair) = bytes.get_mut(index * 2..index * 2 + 2) {
+			pair.copy_from_slice(&value.to_be_bytes());
+		}
+		count += 1;
+	}
+
+	if count != PROQUINT_WORD_COUNT {
+		return Err(Error::InvalidLength {
+			encoding: "proquint",
+			expected: PROQUINT_WORD_COUNT,
+			actual: count,
+		});
+	}
+
+	Ok(Uuid::from_bytes(bytes))
+}
+
+// endregion: --- Proquint
+
+// region:    --- Mnemonic
+
+/// Encodes an existing UUID as a 12-word BIP39 mnemonic (English list, words separated by a space).
+///
+/// The UUID bytes are the 128-bit entropy, so the words are the same as any BIP39 tool for these bytes.
+///
+/// Requires the `mnemonic` feature.
+#[cfg(feature = "mnemonic")]
+pub fn to_mnemonic(uuid: &Uuid) -> String {
+	let checksum = mnemonic_checksum(uuid);
+	let mut words = Vec::with_capacity(MNEMONIC_WORD_COUNT);
+	for i in 0..MNEMONIC_WORD_COUNT {
+		words.push(BIP39_ENGLISH[word_index(uuid.as_u128(), checksum, i)]);
+	}
+	words.join(" ")
+}
+
+/// Decodes a 12-word BIP39 mnemonic into a UUID.
+///
+/// Decoding is case-insensitive and tolerates extra whitespace. As in BIP39, a word can also be
+/// given by its first 4 letters (e.g., `aban` for `abandon`), which are unique in the list.
+///
+/// Returns `Error::InvalidWord` for a word not in the list, `Error::InvalidLength` if not 12 words,
+/// or `Error::ChecksumMismatch` (e.g., swapped or misheard words).
+///
+/// Requires the `mnemonic` feature.
+#[cfg(feature = "mnemonic")]
+pub fn from_mnemonic(s: &str) -> Result<Uuid> {
+	let words = normalized_words(s, char::is_whitespace);
+
+	let mut indexes = [0u16; MNEMONIC_WORD_COUNT];
+	let mut count = 0;
+	for (index, word) in words.enumerate() {
+		let word_index = bip39_index(&word).ok_or_else(|| Error::InvalidWord {
+			encoding: "mnemonic",
+			word: word.clone(),
+			index,
+		})?;
+		if let Some(slot) = indexes.get_mut(index) {
+			*slot = word_index as u16;
+		}
+		count += 1;
+	}
+
+	if count != MNEMONIC_WORD_COUNT {
+		return Err(Error::InvalidLength {
+			encoding: "mnemonic",
+			expected: MNEMONIC_WORD_COUNT,
+			actual: count,
+		});
+	}
+
+	// 11 words of 11 bits, then the last word holds 7 entropy bits and the 4 checksum bits.
+	let (rest, last) = (&indexes[..MNEMONIC_WORD_COUNT - 1], indexes[MNEMONIC_WORD_COUNT - 1]);
+	let high = rest.iter().fold(0u128, |acc, &word_index| (acc << 11) | word_index as u128);
+	let uuid = Uuid::from_u128((high << 7) | (last >> 4) as u128);
+	if mnemonic_checksum(&uuid) != (last & 0xF) as u8 {
+		return Err(Error::ChecksumMismatch { encoding: "mnemonic" });
+	}
+
+	Ok(uuid)
+}
+
+// endregion: --- Mnemonic
+
+// region:    --- Support
+
+/// Lowercased words of `s`, split on `is_separator` (empty words skipped).
+fn normalized_words(s: &str, is_separator: fn(char) -> bool) -> impl Iterator<Item = String> + '_ {
+	s.split(is_separator).filter(|word| !word.is_empty()).map(str::to_lowercase)
+}
+
+fn proquint_value(word: &str) -> Option<u16> {
+	let bytes = word.as_bytes();
+	if bytes.len() != 5 {
+		return None;
+	}
+	let consonant = |b: u8| PROQUINT_CONSONANTS.iter().position(|&c| c == b).map(|v| v as u16);
+	let vowel = |b: u8| PROQUINT_VOWELS.iter().position(|&c| c == b).map(|v| v as u16);
+	Some(
+		consonant(bytes[0])? << 12
+			| vowel(bytes[1])? << 10
+			| consonant(bytes[2])? << 6
+			| vowel(bytes[3])? << 4
+			| consonant(bytes[4])?,
+	)
+}
+
+/// First 4 bits of the SHA-256 of the 16 bytes (BIP39 checksum for 128-bit entropy).
+#[cfg(feature = "mnemonic")]
+fn mnemonic_checksum(uuid: &Uuid) -> u8 {
+	Sha256::digest(uuid.as_bytes())[0] >> 4
+}
+
+/// Index in the word list of the `i`-th 11-bit group of `entropy` followed by `checksum`.
+#[cfg(feature = "mnemonic")]
+fn word_index(entropy: u128, checksum: u8, i: usize) -> usize {
+	// Bits of the group within the 132 bits, from the most significant.
+	let end = (i + 1) * 11;
+	if end <= 128 {
+		(entropy >> (128 - end) & 0x7FF) as usize
+	} else {
+		// Last word, 7 entropy bits then the 4 checksum bits.
+		(((entropy & 0x7F) as usize) << 4) | checksum as usize
+	}
+}
+
+/// Index of a word (or of its unique 4-letter prefix) in the sorted BIP39 list.
+#[cfg(feature = "mnemonic")]
+fn bip39_index(word: &str) -> Option<usize> {
+	if let Ok(index) = BIP39_ENGLISH.binary_search(&word) {
+		return Some(index);
+	}
+	if word.len() != 4 {
+		return None;
+	}
+	let index = BIP39_ENGLISH.partition_point(|&w| w < word);
+	BIP39_ENGLISH.get(index).filter(|w| w.starts_with(word)).map(|_| index)
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::new_v7;
+
+	#[test]
+	fn test_extra_pronounceable_proquint_spec_vectors() -> Result<()> {
+		// -- Setup & Fixtures
+		// 127.0.0.1 is `lusab-babad` and 63.84.220.193 is `gutih-tugad` (proquint spec).
+		let fx_uuid = Uuid::from_bytes([127, 0, 0, 1, 63, 84, 220, 193, 0, 0, 0, 0, 255, 255, 255, 255]);
+
+		// -- Exec
+		let encoded = to_proquint(&fx_uuid);
+
+		// -- Check
+		assert_eq!(encoded, "lusab-babad-gutih-tugad-babab-babab-zuzuz-zuzuz");
+		assert_eq!(from_proquint(&encoded)?, fx_uuid);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_pronounceable_proquint_fuzzy() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_uuid = new_v7();
+		let encoded = to_proquint(&fx_uuid);
+		let fx_spoken = format!("  {}  ", encoded.to_uppercase().replace('-', "   "));
+
+		// -- Exec
+		let uuid = from_proquint(&fx_spoken)?;
+
+		// -- Check
+		assert_eq!(uuid, fx_uuid);
+		assert_eq!(from_proquint(&new_v4_proquint())?.get_version_num(), 4);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_pronounceable_proquint_err() -> Result<()> {
+		// -- Exec
+		let res_word = from_proquint("lusab-babad-gutih-tugad-babab-babab-zuzuz-zuzaa");
+		let res_len = from_proquint("lusab-babad");
+
+		// -- Check
+		assert!(
+			matches!(&res_word, Err(Error::InvalidWord { encoding: "proquint", word, index: 7 }) if word == "zuzaa"),
+			"Got {res_word:?}"
+		);
+		assert!(matches!(
+			res_len,
+			Err(Error::InvalidLength {
+				encoding: "proquint",
+				expected: 8,
+				actual: 2
+			})
+		));
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "mnemonic")]
+	fn test_extra_pronounceable_mnemonic_bip39_vectors() -> Result<()> {
+		// -- Setup & Fixtures
+		// Trezor reference vectors (128-bit entropy).
+		let fx_vectors = [
+			(
+				"00000000000000000000000000000000",
+				"abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+			),
+			(
+				"7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+				"legal winner thank year wave sausage worth useful legal winner thank yellow",
+			),
+			(
+				"80808080808080808080808080808080",
+				"letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+			),
+			(
+				"ffffffffffffffffffffffffffffffff",
+				"zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+			),
+			(
+				"9e885d952ad362caeb4efe34a8e91bd2",
+				"ozone drill grab fiber curtain grace pudding thank cruise elder eight picnic",
+			),
+		];
+
+		for (entropy, mnemonic) in fx_vectors {
+			let uuid = Uuid::parse_str(entropy)?;
+
+			// -- Exec & Check
+			assert_eq!(to_mnemonic(&uuid), mnemonic);
+			assert_eq!(from_mnemonic(mnemonic)?, uuid);
+		}
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "mnemonic")]
+	fn test_extra_pronounceable_mnemonic_fuzzy() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_uuid = new_v7();
+		let mnemonic = to_mnemonic(&fx_uuid);
+		let fx_spoken = format!("\n {} \t", mnemonic.to_uppercase().replace(' ', "  "));
+		let fx_prefixes: Vec<String> = mnemonic.split(' ').map(|w| w.chars().take(4).collect()).collect();
+
+		// -- Exec & Check
+		assert_eq!(from_mnemonic(&fx_spoken)?, fx_uuid);
+		assert_eq!(from_mnemonic(&fx_prefixes.join(" "))?, fx_uuid);
+		assert_eq!(from_mnemonic(&new_v4_mnemonic())?.get_version_num(), 4);
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "mnemonic")]
+	fn test_extra_pronounceable_mnemonic_err() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_swapped =
+			"abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about abandon";
+
+		// -- Exec
+		let res_checksum = from_mnemonic(fx_swapped);
+		let res_word = from_mnemonic("abandon abandon abandonn");
+		let res_len = from_mnemonic("abandon abandon about");
+
+		// -- Check
+		assert!(matches!(
+			res_checksum,
+			Err(Error::ChecksumMismatch { encoding: "mnemonic" })
+		));
+		assert!(matches!(&res_word, Err(Error::InvalidWord { index: 2, .. })));
+		assert!(matches!(
+			res_len,
+			Err(Error::InvalidLength {
+				encoding: "mnemonic",
+				expected: 12,
+				actual: 3
+			})
+		));
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,162 @@
+use std::time::{Duration, SystemTime};
+
+// region:    --- Clock
+
+/// Source of the current time, as a duration since `UNIX_EPOCH`.
+///
+/// Takes `&mut self` so that implementations like `SteppingClock` can advance on each call.
+pub trait Clock {
+	fn now(&mut self) -> Duration;
+}
+
+/// Wall clock (`SystemTime::now()`). A time before `UNIX_EPOCH` is returned as zero.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+	fn now(&mut self) -> Duration {
+		SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default()
+	}
+}
+
+/// Clock always returning the same time.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock {
+	since_epoch: Duration,
+}
+
+impl FixedClock {
+	pub fn new(since_epoch: Duration) -> Self {
+		Self { since_epoch }
+	}
+
+	pub fn from_epoch_ms(epoch_ms: u64) -> Self {
+		Self::new(Duration::from_millis(epoch_ms))
+	}
+}
+
+impl Clock for FixedClock {
+	fn now(&mut self) -> Duration {
+		self.since_epoch
+	}
+}
+
+/// Clock returning its start time on the first call, and advancing by `step` on each following call.
+#[derive(Debug, Clone, Copy)]
+pub struct SteppingClock {
+	next: Duration,
+	step: Duration,
+}
+
+impl SteppingClock {
+	pub fn new(start: Duration, step: Duration) -> Self {
+		Self { next: start, step }
+	}
+
+	pub fn from_epoch_ms(start_epoch_ms: u64, step_ms: u64) -> Self {
+		Self::new(Duration::from_millis(start_epoch_ms), Duration::from_millis(step_ms))
+	}
+}
+
+impl Clock for SteppingClock {
+	fn now(&mut self) -> Duration {
+		let now = self.next;
+		self.next = self.next.saturating_add(self.step);
+		now
+	}
+}
+
+// endregion: --- Clock
+
+// region:    --- RngSource
+
+/// Source of random bytes.
+pub trait RngSource {
+	fn fill_bytes(&mut self, dest: &mut [u8]);
+}
+
+/// Operating system random source (via `getrandom`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OsRng;
+
+impl RngSource for OsRng {
+	fn fill_bytes(&mut self, dest: &mut [u8]) {
+		getrandom::fill(dest).expect("OS random source should be available");
+	}
+}
+
+/// Deterministic random source (SplitMix64) for reproducible ids in tests.
+///
+/// Note: This is NOT cryptographically secure, do not use it for production ids.
+#[derive(Debug, Clone, Copy)]
+pub struct SeededRng {
+	state: u64,
+}
+
+impl SeededRng {
+	pub fn new(seed: u64) -> Self {
+		Self { state: seed }
+	}
+
+	fn next_u64(&mut self) -> u64 {
+		self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+		let mut z = self.state;
+		z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+		z ^ (z >> 31)
+	}
+}
+
+impl RngSource for SeededRng {
+	fn fill_bytes(&mut self, dest: &mut [u8]) {
+		for chunk in dest.chunks_mut(8) {
+			let bytes = self.next_u64().to_be_bytes();
+			chunk.copy_from_slice(&bytes[..chunk.len()]);
+		}
+	}
+}
+
+// endregion: --- RngSource
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+
+	#[test]
+	fn test_extra_source_stepping_clock_simple() -> Result<()> {
+		// -- Setup & Fixtures
+		let mut clock = SteppingClock::from_epoch_ms(1_000, 5);
+
+		// -- Exec
+		let times: Vec<u128> = (0..3).map(|_| clock.now().as_millis()).collect();
+
+		// -- Check
+		assert_eq!(times, vec![1_000, 1_005, 1_010]);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_source_seeded_rng_reproducible() -> Result<()> {
+		// -- Setup & Fixtures
+		let mut rng_a = SeededRng::new(42);
+		let mut rng_b = SeededRng::new(42);
+		let mut rng_c = SeededRng::new(43);
+		let (mut buf_a, mut buf_b, mut buf_c) = ([0u8; 20], [0u8; 20], [0u8; 20]);
+
+		// -- Exec
+		rng_a.fill_bytes(&mut buf_a);
+		rng_b.fill_bytes(&mut buf_b);
+		rng_c.fill_bytes(&mut buf_c);
+
+		// -- Check
+		assert_eq!(buf_a, buf_b, "Same seed should produce the same bytes");
+		assert_ne!(buf_a, buf_c, "Different seeds should produce different bytes");
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,338 @@
+//! TypeID, a type prefix and the lowercase Crockford Base32 of a UUID (e.g., `user_01h455vb4pex5vsknk084sn02q`).
+//!
+//! See the [TypeID specification](https://github.com/jetify-com/typeid/tree/main/spec).
+
+use crate::extra_base32::{B32_UUID_LEN, from_b32};
+use crate::extra_prefixed::check_prefix;
+use crate::extra_uuid::new_v7;
+use crate::{EncodedB32, Encoding, Error, Result, support};
+use core::fmt;
+use core::str::FromStr;
+use uuid::Uuid;
+
+/// Max length of a TypeID prefix.
+pub const TYPEID_PREFIX_MAX_LEN: usize = 63;
+
+/// Lowercase Crockford Base32 alphabet, the only characters allowed in a TypeID suffix.
+const SUFFIX_ALPHABET: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
+
+// region:    --- Functions
+
+/// Generates a new UUID version 7 and formats it as a TypeID with `prefix` (e.g., `user_01h455vb4pex5vsknk084sn02q`).
+///
+/// Returns `Error::InvalidPrefix` if `prefix` is not valid (see `to_typeid`).
+pub fn new_typeid(prefix: &str) -> Result<String> {
+	to_typeid(prefix, &new_v7())
+}
+
+/// Formats an existing UUID as a TypeID with `prefix`.
+///
+/// The prefix is at most 63 lowercase ASCII letters or `_`, starting and ending with a letter.
+/// An empty prefix gives the bare 26-char suffix (no `_` separator).
+pub fn to_typeid(prefix: &str, uuid: &Uuid) -> Result<String> {
+	validate_prefix(prefix)?;
+
+	let suffix = EncodedB32::new(uuid);
+	let mut typeid = String::with_capacity(prefix.len() + 1 + B32_UUID_LEN);
+	if !prefix.is_empty() {
+		typeid.push_str(prefix);
+		typeid.push('_');
+	}
+	typeid.extend(suffix.chars().map(|ch| ch.to_ascii_lowercase()));
+	Ok(typeid)
+}
+
+/// Parses a TypeID into its prefix (empty if none) and UUID.
+///
+/// Parsing is strict, the suffix must be 26 lowercase Crockford Base32 chars, starting with `0` to `7`.
+pub fn parse_typeid(s: &str) -> Result<(&str, Uuid)> {
+	let (prefix, suffix) = match s.rsplit_once('_') {
+		Some(("", _)) => {
+			return Err(Error::InvalidPrefix {
+				prefix: String::new(),
+				reason: "empty prefix with separator",
+			});
+		}
+		Some((prefix, suffix)) => (prefix, suffix),
+		None => ("", s),
+	};
+	validate_prefix(prefix)?;
+
+	let uuid = decode_suffix(suffix, s.len() - suffix.len())?;
+	Ok((prefix, uuid))
+}
+
+/// Parses a TypeID and checks that its prefix is `expected_prefix`, returning the UUID.
+///
+/// Returns `Error::PrefixMismatch` if the prefix differs (e.g., an `org_` id passed as a `user_` id).
+pub fn parse_typeid_expect_prefix(s: &str, expected_prefix: &str) -> Result<Uuid> {
+	let (prefix, uuid) = parse_typeid(s)?;
+	check_prefix(prefix, expected_prefix)?;
+	Ok(uuid)
+}
+
+// endregion: --- Functions
+
+// region:    --- TypeId
+
+/// Owned TypeID, a validated prefix and a UUID.
+///
+/// Displays (and parses with `FromStr`) as the TypeID string,
+/// and converts to/from the other encodings with `encode` and `from_encoded`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TypeId {
+	prefix: String,
+	uuid: Uuid,
+}
+
+/// Constructors
+impl TypeId {
+	/// Creates a TypeID of a new UUID version 7.
+	pub fn new(prefix: impl Into<String>) -> Result<Self> {
+		Self::from_uuid(prefix, new_v7())
+	}
+
+	/// Creates a TypeID of an existing UUID.
+	pub fn from_uuid(prefix: impl Into<String>, uuid: Uuid) -> Result<Self> {
+		let prefix = prefix.into();
+		validate_prefix(&prefix)?;
+		Ok(Self { prefix, uuid })
+	}
+
+	/// Creates a TypeID from the UUID encoded with `encoding` (e.g., a Base58 id of an older API).
+	pub fn from_encoded(prefix: impl Into<String>, encoding: Encoding, s: &str) -> Result<Self> {
+		Self::from_uuid(prefix, encoding.decode(s)?)
+	}
+
+	/// Parses a TypeID and checks that its prefix is `expected_prefix`.
+	pub fn parse_expect_prefix(s: &str, expected_prefix: &str) -> Result<Self> {
+		let typeid: TypeId = s.parse()?;
+		check_prefix(&typeid.prefix, expected_prefix)?;
+		Ok(typeid)
+	}
+}
+
+/// Getters & Encoders
+impl TypeId {
+	pub fn prefix(&self) -> &str {
+		&self.prefix
+	}
+
+	pub fn uuid(&self) -> Uuid {
+		self.uuid
+	}
+
+	/// Encodes the UUID (without the prefix) with `encoding`, e.g., `Encoding::B58`.
+	pub fn encode(&self, encoding: Encoding) -> String {
+		encoding.encode(&self.uuid)
+	}
+}
+
+impl fmt::Display for TypeId {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if !self.prefix.is_empty() {
+			write!(f, "{}_", self.prefix)?;
+		}
+		let mut suffix = [0u8; B32_UUID_LEN];
+		suffix.copy_from_slice(EncodedB32::new(&self.uuid).as_bytes());
+		suffix.make_ascii_lowercase();
+		f.write_str(support::ascii_str(&suffix))
+	}
+}
+
+impl FromStr for TypeId {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		let (prefix, uuid) = parse_typeid(s)?;
+		Ok(Self {
+			prefix: prefix.to_string(),
+			uuid,
+		})
+	}
+}
+
+impl From<TypeId> for Uuid {
+	fn from(typeid: TypeId) -> Self {
+		typeid.uuid
+	}
+}
+
+// endregion: --- TypeId
+
+// region:    --- Support
+
+fn validate_prefix(prefix: &str) -> Result<()> {
+	let invalid = |reason: &'static str| Error::InvalidPrefix {
+		prefix: prefix.to_string(),
+		reason,
+	};
+
+	if prefix.len() > TYPEID_PREFIX_MAX_LEN {
+		return Err(invalid("longer than 63 chars"));
+	}
+	if !prefix.bytes().all(|b| b.is_ascii_lowercase() || b == b'_') {
+		return Err(invalid("only lowercase ASCII letters and '_' are allowed"));
+	}
+	if prefix.starts_with('_') || prefix.ends_with('_') {
+		return Err(invalid("must start and end with a letter"));
+	}
+	Ok(())
+}
+
+/// Decodes the suffix, `offset` being its index in the TypeID (for error reporting).
+fn decode_suffix(suffix: &str, offset: usize) -> Result<Uuid> {
+	if suffix.len() != B32_UUID_LEN {
+		return Err(Error::InvalidLength {
+			encoding: "typeid",
+			expected: B32_UUID_LEN,
+			actual: suffix.len(),
+		});
+	}
+	if let Some((index, ch)) = suffix
+		.char_indices()
+		.find(|&(_, ch)| !ch.is_ascii() || !SUFFIX_ALPHABET.contains(&(ch as u8)))
+	{
+		return Err(Error::InvalidCharacter {
+			encoding: "typeid",
+			ch,
+			index: offset + index,
+		});
+	}
+	// 26 * 5 = 130 bits, so the first char holds the top 3 bits only.
+	if suffix.as_bytes()[0] > b'7' {
+		return Err(Error::ValueOverflow { encoding: "typeid" });
+	}
+
+	from_b32(suffix)
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+
+	#[test]
+	fn test_extra_typeid_to_typeid_spec_vectors() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_vectors = [
+			("", "00000000-0000-0000-0000-000000000000", "00000000000000000000000000"),
+			("", "ffffffff-ffff-ffff-ffff-ffffffffffff", "7zzzzzzzzzzzzzzzzzzzzzzzzz"),
+			(
+				"prefix",
+				"01890a5d-ac96-774b-bcce-b302099a8057",
+				"prefix_01h455vb4pex5vsknk084sn02q",
+			),
+			(
+				"pre_fix",
+				"00000000-0000-0000-0000-000000000000",
+				"pre_fix_00000000000000000000000000",
+			),
+		];
+
+		for (prefix, uuid, typeid) in fx_vectors {
+			let uuid = Uuid::parse_str(uuid)?;
+
+			// -- Exec
+			let res = to_typeid(prefix, &uuid)?;
+			let (parsed_prefix, parsed_uuid) = parse_typeid(typeid)?;
+
+			// -- Check
+			assert_eq!(res, typeid);
+			assert_eq!((parsed_prefix, parsed_uuid), (prefix, uuid));
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_typeid_new_typeid_simple() -> Result<()> {
+		// -- Exec
+		let typeid = new_typeid("user")?;
+
+		// -- Check
+		assert_eq!(typeid.len(), 5 + 26);
+		let uuid = parse_typeid_expect_prefix(&typeid, "user")?;
+		assert_eq!(uuid.get_version_num(), 7);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_typeid_parse_err() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_invalid = [
+			("User_01h455vb4pex5vsknk084sn02q", "prefix uppercase"),
+			("_01h455vb4pex5vsknk084sn02q", "empty prefix with separator"),
+			("user__01h455vb4pex5vsknk084sn02q", "prefix ending with '_'"),
+			("user_01h455vb4pex5vsknk084sn02", "suffix too short"),
+			("user_01H455VB4PEX5VSKNK084SN02Q", "suffix uppercase"),
+			("user_01h455vb4pex5vsknk084sn0lq", "suffix 'l' not in alphabet"),
+			("user_8zzzzzzzzzzzzzzzzzzzzzzzzz", "suffix overflow"),
+		];
+
+		for (typeid, case) in fx_invalid {
+			// -- Exec
+			let res = parse_typeid(typeid);
+
+			// -- Check
+			assert!(res.is_err(), "Should fail for {case}: {typeid}");
+		}
+
+		let res = parse_typeid("user_01h455vb4pex5vsknk084sn0lq");
+		assert!(matches!(
+			res,
+			Err(Error::InvalidCharacter {
+				encoding: "typeid",
+				ch: 'l',
+				index: 29
+			})
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_typeid_expect_prefix_err_mismatch() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_typeid = to_typeid("org", &new_v7())?;
+
+		// -- Exec
+		let res = parse_typeid_expect_prefix(&fx_typeid, "user");
+
+		// -- Check
+		match res {
+			Err(Error::PrefixMismatch { expected, actual }) => {
+				assert_eq!(expected, "user");
+				assert_eq!(actual, "org");
+			}
+			other => return Err(format!("Expected PrefixMismatch, got {other:?}").into()),
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_typeid_type_id_encodings() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_typeid = TypeId::new("user")?;
+
+		// -- Exec
+		let b58 = fx_typeid.encode(Encoding::B58);
+		let from_b58 = TypeId::from_encoded("user", Encoding::B58, &b58)?;
+		let parsed = TypeId::parse_expect_prefix(&fx_typeid.to_string(), "user")?;
+
+		// -- Check
+		assert_eq!(from_b58, fx_typeid);
+		assert_eq!(parsed, fx_typeid);
+		assert_eq!(
+			crate::from_b64url_nopad(&fx_typeid.encode(Encoding::B64UrlNoPad))?,
+			fx_typeid.uuid()
+		);
+		assert_eq!(fx_typeid.to_string(), to_typeid("user", &fx_typeid.uuid())?);
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
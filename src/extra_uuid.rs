@@ -1,6 +1,15 @@
+use crate::extra_generator::next_shared_v7;
+use crate::extra_source::{OsRng, RngSource};
 use crate::{Error, Result};
+use std::time::{Duration, SystemTime};
 use uuid::Uuid;
 
+/// Max epoch milliseconds that fit in the 48-bit v7 timestamp.
+const V7_MAX_EPOCH_MS: i64 = (1 << 48) - 1;
+
+/// 100ns intervals between the Gregorian epoch (1582-10-15) of v1/v6 and `UNIX_EPOCH`.
+const GREGORIAN_TO_UNIX_100NS: i64 = 0x01B2_1DD2_1381_4000;
+
 // region:    --- Raw Uuid
 
 /// Generates a new UUID version 4.
@@ -12,10 +21,12 @@ pub fn new_v4() -> Uuid {
 
 /// Alias to `now_v7`
 pub fn new_v7() -> Uuid {
-	Uuid::now_v7()
+	next_shared_v7()
 }
 
 /// If `uuid` is a version-7 UUID, return the epoch time millisecond precision.
+///
+/// See `to_any_time_epoch_ms` for v1 and v6 UUIDs.
 pub fn to_time_epoch_ms(uuid: &Uuid) -> Result<i64> {
 	// make sure this really is a v7 UUID
 	if uuid.get_version_num() != 7 {
@@ -33,12 +44,226 @@ pub fn to_time_epoch_ms(uuid: &Uuid) -> Result<i64> {
 /// Generates a new UUID version 7 with the now time.
 ///
 /// UUID version 7 is a time-ordered UUID which is well-suited for use as a database key.
-/// This function uses `Uuid::now_v7()` which is suitable for most cases.
+/// Ids are strictly increasing within the process (from a shared `V7Generator`
+/// with `V7Method::MonotonicRandom`), even across threads.
 pub fn now_v7() -> Uuid {
-	Uuid::now_v7()
+	next_shared_v7()
 }
 // endregion: --- Raw Uuid
 
+// region:    --- Gregorian (v1 & v6)
+
+/// Generates a new UUID version 1 (Gregorian time, then clock sequence and `node_id`).
+///
+/// Use a stable `node_id` per process or host (e.g., from `random_node_id()` at startup).
+pub fn new_v1(node_id: &[u8; 6]) -> Uuid {
+	Uuid::now_v1(node_id)
+}
+
+/// Generates a new UUID version 6 (same fields as v1, reordered so it sorts by time).
+pub fn new_v6(node_id: &[u8; 6]) -> Uuid {
+	Uuid::now_v6(node_id)
+}
+
+/// Returns a random node id with the multicast bit set, so it cannot collide with a real MAC address (RFC 9562).
+pub fn random_node_id() -> [u8; 6] {
+	let mut node_id = [0u8; 6];
+	OsRng.fill_bytes(&mut node_id);
+	node_id[0] |= 0x01;
+	node_id
+}
+
+/// Converts a UUID version 1 to version 6, losslessly (same timestamp, clock sequence, and node).
+///
+/// Returns `Error::UnexpectedVersion` if `uuid` is not a version 1.
+pub fn v1_to_v6(uuid: &Uuid) -> Result<Uuid> {
+	expect_version(uuid, 1)?;
+	let ticks = gregorian_ticks(uuid) as u128;
+
+	let time_high = (ticks >> 28) & 0xFFFF_FFFF;
+	let time_mid = (ticks >> 12) & 0xFFFF;
+	let time_low = ticks & 0xFFF;
+	let value = (time_high << 96) | (time_mid << 80) | (0x6 << 76) | (time_low << 64) | (uuid.as_u128() & LOW_64_BITS);
+
+	Ok(Uuid::from_u128(value))
+}
+
+/// Converts a UUID version 6 back to version 1, losslessly.
+///
+/// Returns `Error::UnexpectedVersion` if `uuid` is not a version 6.
+pub fn v6_to_v1(uuid: &Uuid) -> Result<Uuid> {
+	expect_version(uuid, 6)?;
+	let ticks = gregorian_ticks(uuid) as u128;
+
+	let time_low = ticks & 0xFFFF_FFFF;
+	let time_mid = (ticks >> 32) & 0xFFFF;
+	let time_high = (ticks >> 48) & 0xFFF;
+	let value = (time_low << 96) | (time_mid << 80) | (0x1 << 76) | (time_high << 64) | (uuid.as_u128() & LOW_64_BITS);
+
+	Ok(Uuid::from_u128(value))
+}
+
+/// Returns the epoch time in milliseconds of any time-based UUID (v1, v6, or v7).
+///
+/// v1 and v6 timestamps are 100ns intervals since the Gregorian epoch (1582-10-15),
+/// so they are truncated to milliseconds (and negative before `UNIX_EPOCH`).
+///
+/// Returns `Error::FailExtractTimeNotTimeBased` for the other versions.
+pub fn to_any_time_epoch_ms(uuid: &Uuid) -> Result<i64> {
+	match uuid.get_version_num() {
+		7 => to_time_epoch_ms(uuid),
+		1 | 6 => {
+			let ticks = gregorian_ticks(uuid) as i64;
+			Ok((ticks - GREGORIAN_TO_UNIX_100NS).div_euclid(10_000))
+		}
+		_ => Err(Error::FailExtractTimeNotTimeBased(*uuid)),
+	}
+}
+
+/// Gregorian timestamp of v1/v6 for a duration since `UNIX_EPOCH`, with `clock_seq` (14 bits) as counter.
+pub(crate) fn gregorian_timestamp(since_epoch: Duration, clock_seq: u16) -> uuid::Timestamp {
+	let ticks = (since_epoch.as_nanos() / 100) as u64 + GREGORIAN_TO_UNIX_100NS as u64;
+	uuid::Timestamp::from_gregorian_time(ticks, clock_seq & 0x3FFF)
+}
+
+/// Variant, clock sequence, and node (same position in v1 and v6).
+const LOW_64_BITS: u128 = u64::MAX as u128;
+
+/// Returns the 60-bit Gregorian timestamp of a v1 or v6 UUID (the caller checks the version).
+fn gregorian_ticks(uuid: &Uuid) -> u64 {
+	let value = uuid.as_u128();
+	let (top_32, mid_16, low_12) = (
+		(value >> 96) as u64 & 0xFFFF_FFFF,
+		(value >> 80) as u64 & 0xFFFF,
+		(value >> 64) as u64 & 0xFFF,
+	);
+	if uuid.get_version_num() == 1 {
+		// time_low (32) | time_mid (16) | version | time_high (12)
+		(low_12 << 48) | (mid_16 << 32) | top_32
+	} else {
+		// time_high (32) | time_mid (16) | version | time_low (12)
+		(top_32 << 28) | (mid_16 << 12) | low_12
+	}
+}
+
+pub(crate) fn expect_version(uuid: &Uuid, expected: u8) -> Result<()> {
+	let actual = uuid.get_version_num() as u8;
+	if actual != expected {
+		return Err(Error::UnexpectedVersion { expected, actual });
+	}
+	Ok(())
+}
+
+// endregion: --- Gregorian (v1 & v6)
+
+// region:    --- Name Based
+
+/// Namespace for fully-qualified domain names (RFC 9562).
+pub const NAMESPACE_DNS: Uuid = Uuid::NAMESPACE_DNS;
+
+/// Namespace for URLs (RFC 9562).
+pub const NAMESPACE_URL: Uuid = Uuid::NAMESPACE_URL;
+
+/// Namespace for ISO OIDs (RFC 9562).
+pub const NAMESPACE_OID: Uuid = Uuid::NAMESPACE_OID;
+
+/// Namespace for X.500 DNs (RFC 9562).
+pub const NAMESPACE_X500: Uuid = Uuid::NAMESPACE_X500;
+
+/// Generates a UUID version 5 (SHA-1) for `name` within `namespace`.
+///
+/// The same namespace and name always give the same UUID (e.g., for idempotent imports).
+/// For multi-part names (e.g., tenant + email), use `NameBuilder` to avoid collisions.
+pub fn new_v5(namespace: &Uuid, name: impl AsRef<[u8]>) -> Uuid {
+	Uuid::new_v5(namespace, name.as_ref())
+}
+
+/// Generates a UUID version 3 (MD5) for `name` within `namespace`.
+///
+/// Prefer `new_v5`, unless version 3 is needed for compatibility.
+pub fn new_v3(namespace: &Uuid, name: impl AsRef<[u8]>) -> Uuid {
+	Uuid::new_v3(namespace, name.as_ref())
+}
+
+// endregion: --- Name Based
+
+// region:    --- From Time
+
+/// Generates a new UUID version 7 for the given epoch milliseconds (e.g., for backfilling historical rows).
+///
+/// Returns `Error::V7EpochMsOutOfRange` if `epoch_ms` is negative or does not fit in 48 bits.
+pub fn v7_from_epoch_ms(epoch_ms: i64) -> Result<Uuid> {
+	v7_from_epoch_ms_with_rng(epoch_ms, &mut OsRng)
+}
+
+/// Same as `v7_from_epoch_ms`, with the random bits drawn from `rng` (e.g., for `UuidGenerator`).
+pub(crate) fn v7_from_epoch_ms_with_rng(epoch_ms: i64, rng: &mut impl RngSource) -> Result<Uuid> {
+	if !(0..=V7_MAX_EPOCH_MS).contains(&epoch_ms) {
+		return Err(Error::V7EpochMsOutOfRange {
+			epoch_ms: epoch_ms as i128,
+		});
+	}
+
+	let mut random_bytes = [0u8; 10];
+	rng.fill_bytes(&mut random_bytes);
+	Ok(uuid::Builder::from_unix_timestamp_millis(epoch_ms as u64, &random_bytes).into_uuid())
+}
+
+/// Generates a new UUID version 7 for the given `SystemTime` (millisecond precision).
+///
+/// Returns `Error::V7EpochMsOutOfRange` if the time is before `UNIX_EPOCH` or does not fit in 48 bits.
+pub fn v7_from_system_time(time: SystemTime) -> Result<Uuid> {
+	match time.duration_since(SystemTime::UNIX_EPOCH) {
+		Ok(since_epoch) => v7_from_duration(since_epoch),
+		Err(before_epoch) => {
+			// Floor (e.g., 0.5 ms before `UNIX_EPOCH` is -1), so the reported value is always negative.
+			let before = before_epoch.duration();
+			let ceil_ms = before.as_millis() + u128::from(before.subsec_nanos() % 1_000_000 != 0);
+			Err(Error::V7EpochMsOutOfRange {
+				epoch_ms: -(ceil_ms as i128),
+			})
+		}
+	}
+}
+
+/// Generates a new UUID version 7 for the given duration since `UNIX_EPOCH` (millisecond precision).
+///
+/// Returns `Error::V7EpochMsOutOfRange` if the duration does not fit in 48 bits of milliseconds.
+pub fn v7_from_duration(since_epoch: Duration) -> Result<Uuid> {
+	let epoch_ms = since_epoch.as_millis();
+	let epoch_ms = i64::try_from(epoch_ms).map_err(|_| Error::V7EpochMsOutOfRange {
+		epoch_ms: epoch_ms as i128,
+	})?;
+	v7_from_epoch_ms(epoch_ms)
+}
+
+/// Returns the smallest UUID version 7 for the given epoch milliseconds (all random/counter bits zero).
+///
+/// Useful as the lower bound of a time-range query (e.g., `WHERE id BETWEEN $min AND $max`).
+pub fn v7_min_for_epoch_ms(epoch_ms: i64) -> Result<Uuid> {
+	v7_with_fill_for_epoch_ms(epoch_ms, 0x00)
+}
+
+/// Returns the largest UUID version 7 for the given epoch milliseconds (all random/counter bits one).
+///
+/// Useful as the upper bound of a time-range query (e.g., `WHERE id BETWEEN $min AND $max`).
+pub fn v7_max_for_epoch_ms(epoch_ms: i64) -> Result<Uuid> {
+	v7_with_fill_for_epoch_ms(epoch_ms, 0xFF)
+}
+
+fn v7_with_fill_for_epoch_ms(epoch_ms: i64, fill: u8) -> Result<Uuid> {
+	if !(0..=V7_MAX_EPOCH_MS).contains(&epoch_ms) {
+		return Err(Error::V7EpochMsOutOfRange {
+			epoch_ms: epoch_ms as i128,
+		});
+	}
+
+	// Note: The builder sets the version and variant bits over the fill bytes.
+	Ok(uuid::Builder::from_unix_timestamp_millis(epoch_ms as u64, &[fill; 10]).into_uuid())
+}
+
+// endregion: --- From Time
+
 // region:    --- Tests
 
 #[cfg(test)]
@@ -62,6 +287,101 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_extra_uuid_new_v5_v3_known_values() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_name = "www.example.com";
+
+		// -- Exec
+		let uuid_v5 = new_v5(&NAMESPACE_DNS, fx_name);
+		let uuid_v3 = new_v3(&NAMESPACE_DNS, fx_name.as_bytes());
+
+		// -- Check
+		assert_eq!(uuid_v5.to_string(), "2ed6657d-e927-568b-95e1-2665a8aea6a2");
+		assert_eq!(uuid_v3.to_string(), "5df41881-3aed-3515-88a7-2f4a814cf09e");
+		assert_eq!(new_v5(&NAMESPACE_DNS, fx_name), uuid_v5, "v5 should be deterministic");
+		assert_ne!(new_v5(&NAMESPACE_URL, fx_name), uuid_v5);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_uuid_v1_v6_rfc_vectors() -> Result<()> {
+		// -- Setup & Fixtures
+		// RFC 9562 test vectors (Appendix A.1 and A.5), both at 2022-02-22T19:22:22Z.
+		let fx_v1 = Uuid::parse_str("C232AB00-9414-11EC-B3C8-9F6BDECED846")?;
+		let fx_v6 = Uuid::parse_str("1EC9414C-232A-6B00-B3C8-9F6BDECED846")?;
+
+		// -- Exec
+		let v6 = v1_to_v6(&fx_v1)?;
+		let v1 = v6_to_v1(&fx_v6)?;
+
+		// -- Check
+		assert_eq!(v6, fx_v6);
+		assert_eq!(v1, fx_v1);
+		assert_eq!(to_any_time_epoch_ms(&fx_v1)?, 1_645_557_742_000);
+		assert_eq!(to_any_time_epoch_ms(&fx_v6)?, 1_645_557_742_000);
+		assert!(matches!(
+			to_time_epoch_ms(&fx_v1),
+			Err(Error::FailExtractTimeNoUuidV7(_))
+		));
+		assert!(matches!(
+			to_any_time_epoch_ms(&new_v4()),
+			Err(Error::FailExtractTimeNotTimeBased(_))
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_uuid_new_v1_v6_now() -> Result<()> {
+		// -- Setup & Fixtures
+		let node_id = random_node_id();
+		let now_ms = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?.as_millis() as i64;
+
+		// -- Exec
+		let uuid_v1 = new_v1(&node_id);
+		let uuid_v6 = new_v6(&node_id);
+
+		// -- Check
+		assert_eq!(uuid_v1.get_version(), Some(Version::Mac));
+		assert_eq!(uuid_v6.get_version(), Some(Version::SortMac));
+		assert_eq!(&uuid_v1.as_bytes()[10..], &node_id);
+		assert_eq!(node_id[0] & 0x01, 0x01, "Random node id should have the multicast bit");
+		for uuid in [uuid_v1, uuid_v6] {
+			let epoch_ms = to_any_time_epoch_ms(&uuid)?;
+			assert!(
+				(epoch_ms - now_ms).abs() < 10_000,
+				"{uuid} time {epoch_ms} should be near {now_ms}"
+			);
+		}
+		assert_eq!(
+			v6_to_v1(&v1_to_v6(&uuid_v1)?)?,
+			uuid_v1,
+			"Conversion should be lossless"
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_uuid_v1_to_v6_err_version() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_v4 = new_v4();
+
+		// -- Exec
+		let res_v1_to_v6 = v1_to_v6(&fx_v4);
+		let res_v6_to_v1 = v6_to_v1(&fx_v4);
+
+		// -- Check
+		assert!(matches!(
+			res_v1_to_v6,
+			Err(Error::UnexpectedVersion { expected: 1, actual: 4 })
+		));
+		assert!(matches!(
+			res_v6_to_v1,
+			Err(Error::UnexpectedVersion { expected: 6, actual: 4 })
+		));
+		Ok(())
+	}
+
 	#[test]
 	fn test_extra_uuid_new_v7_simple() -> Result<()> {
 		// -- Setup & Fixtures
@@ -79,8 +399,8 @@ mod tests {
 		assert_eq!(uuid_v7_1.get_version(), Some(Version::SortRand));
 		assert_eq!(uuid_v7_2.get_version(), Some(Version::SortRand));
 		// V7 UUIDs generated sequentially should be ordered
-		// Note: Uuid::now_v7() guarantees monotonicity for calls within the same millisecond
-		// by incrementing a sequence counter. If calls are in different milliseconds,
+		// Note: The shared generator guarantees monotonicity for calls within the same millisecond
+		// by incrementing the random bits. If calls are in different milliseconds,
 		// the time component will ensure ordering.
 		assert!(
 			uuid_v7_1 < uuid_v7_2,
@@ -98,7 +418,7 @@ mod tests {
 		// relative to execution speed, and that new_v7() has a distinct timestamp.
 		std::thread::sleep(std::time::Duration::from_millis(1));
 
-		let uuid_v7 = new_v7(); // Uuid::now_v7() is used here
+		let uuid_v7 = new_v7(); // The shared generator is used here
 
 		std::thread::sleep(std::time::Duration::from_millis(1));
 		let t1_ms = system_time_to_ms(SystemTime::now())?;
@@ -150,6 +470,105 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_extra_uuid_v7_from_epoch_ms_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_epoch_ms = [0, 1_262_304_000_000, V7_MAX_EPOCH_MS];
+
+		for epoch_ms in fx_epoch_ms {
+			// -- Exec
+			let uuid = v7_from_epoch_ms(epoch_ms)?;
+
+			// -- Check
+			assert_eq!(uuid.get_version(), Some(Version::SortRand));
+			assert_eq!(to_time_epoch_ms(&uuid)?, epoch_ms);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_uuid_v7_from_epoch_ms_err_out_of_range() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_epoch_ms = [-1, V7_MAX_EPOCH_MS + 1, i64::MAX];
+
+		for epoch_ms in fx_epoch_ms {
+			// -- Exec
+			let res = v7_from_epoch_ms(epoch_ms);
+
+			// -- Check
+			match res {
+				Err(Error::V7EpochMsOutOfRange { epoch_ms: err_ms }) => assert_eq!(err_ms, epoch_ms as i128),
+				other => panic!("Expected V7EpochMsOutOfRange for {epoch_ms}, got {other:?}"),
+			}
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_uuid_v7_from_system_time_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let time = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+
+		// -- Exec
+		let uuid_from_time = v7_from_system_time(time)?;
+		let uuid_from_duration = v7_from_duration(Duration::from_millis(1_700_000_000_123))?;
+
+		// -- Check
+		assert_eq!(to_time_epoch_ms(&uuid_from_time)?, 1_700_000_000_123);
+		assert_eq!(to_time_epoch_ms(&uuid_from_duration)?, 1_700_000_000_123);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_uuid_v7_from_system_time_err_before_epoch() -> Result<()> {
+		// -- Setup & Fixtures
+		let time = SystemTime::UNIX_EPOCH - Duration::from_millis(5);
+		let fx_sub_ms_time = SystemTime::UNIX_EPOCH - Duration::from_micros(500);
+		let fx_between_ms_time = SystemTime::UNIX_EPOCH - Duration::from_micros(5_500);
+
+		// -- Exec
+		let res = v7_from_system_time(time);
+
+		// -- Check
+		assert!(
+			matches!(res, Err(Error::V7EpochMsOutOfRange { epoch_ms: -5 })),
+			"Expected V7EpochMsOutOfRange, got {res:?}"
+		);
+		assert!(matches!(
+			v7_from_system_time(fx_sub_ms_time),
+			Err(Error::V7EpochMsOutOfRange { epoch_ms: -1 })
+		));
+		assert!(matches!(
+			v7_from_system_time(fx_between_ms_time),
+			Err(Error::V7EpochMsOutOfRange { epoch_ms: -6 })
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_uuid_v7_min_max_for_epoch_ms_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_epoch_ms = 1_700_000_000_000;
+
+		// -- Exec
+		let min = v7_min_for_epoch_ms(fx_epoch_ms)?;
+		let max = v7_max_for_epoch_ms(fx_epoch_ms)?;
+
+		// -- Check
+		assert_eq!(min.get_version(), Some(Version::SortRand));
+		assert_eq!(max.get_version(), Some(Version::SortRand));
+		assert_eq!(min.get_variant(), uuid::Variant::RFC4122);
+		assert_eq!(max.get_variant(), uuid::Variant::RFC4122);
+		assert_eq!(min.as_u128() & ((1 << 80) - 1), 0x7000_8000_0000_0000_0000);
+		assert_eq!(max.as_u128() & ((1 << 80) - 1), 0x7FFF_BFFF_FFFF_FFFF_FFFF);
+		assert_eq!(to_time_epoch_ms(&min)?, fx_epoch_ms);
+		assert_eq!(to_time_epoch_ms(&max)?, fx_epoch_ms);
+		let uuid = v7_from_epoch_ms(fx_epoch_ms)?;
+		assert!(min <= uuid && uuid <= max);
+		assert!(v7_max_for_epoch_ms(fx_epoch_ms - 1)? < min);
+		Ok(())
+	}
+
 	// region:    --- Support
 	fn system_time_to_ms(st: SystemTime) -> Result<i64> {
 		Ok(st
@@ -0,0 +1,116 @@
+use crate::extra_uuid::to_time_epoch_ms;
+use crate::{Result, to_b32, to_b58, to_b58_sortable, to_b62, to_b64, to_b64url, to_b64url_nopad};
+use uuid::Uuid;
+
+/// Extension trait to encode an existing `Uuid` (e.g., loaded from a database).
+///
+/// ```ignore
+/// use uuid_extra::UuidExtra as _;
+///
+/// let id_b58 = uuid.to_b58();
+/// let epoch_ms = uuid.epoch_ms()?;
+/// ```
+pub trait UuidExtra {
+	/// Same as `to_b58(&uuid)`.
+	fn to_b58(&self) -> String;
+
+	/// Same as `to_b58_sortable(&uuid)`.
+	fn to_b58_sortable(&self) -> String;
+
+	/// Same as `to_b62(&uuid)`.
+	fn to_b62(&self) -> String;
+
+	/// Same as `to_b32(&uuid)`.
+	fn to_b32(&self) -> String;
+
+	/// Same as `to_b64(&uuid)`.
+	fn to_b64(&self) -> String;
+
+	/// Same as `to_b64url(&uuid)`.
+	fn to_b64url(&self) -> String;
+
+	/// Same as `to_b64url_nopad(&uuid)`.
+	fn to_b64url_nopad(&self) -> String;
+
+	/// Same as `to_time_epoch_ms(&uuid)` (UUID v7 only).
+	fn epoch_ms(&self) -> Result<i64>;
+}
+
+impl UuidExtra for Uuid {
+	fn to_b58(&self) -> String {
+		to_b58(self)
+	}
+
+	fn to_b58_sortable(&self) -> String {
+		to_b58_sortable(self)
+	}
+
+	fn to_b62(&self) -> String {
+		to_b62(self)
+	}
+
+	fn to_b32(&self) -> String {
+		to_b32(self)
+	}
+
+	fn to_b64(&self) -> String {
+		to_b64(self)
+	}
+
+	fn to_b64url(&self) -> String {
+		to_b64url(self)
+	}
+
+	fn to_b64url_nopad(&self) -> String {
+		to_b64url_nopad(self)
+	}
+
+	fn epoch_ms(&self) -> Result<i64> {
+		to_time_epoch_ms(self)
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::{Error, from_b58, from_b64, from_b64url, from_b64url_nopad, v7_from_epoch_ms};
+
+	#[test]
+	fn test_extra_uuid_ext_to_encodings_roundtrip() -> Result<()> {
+		// -- Setup & Fixtures
+		// As if loaded from a database.
+		let fx_uuid = Uuid::parse_str("0188ee2a-6f4e-7a2b-9c3d-1e2f3a4b5c6d")?;
+
+		// -- Exec & Check
+		assert_eq!(from_b58(&fx_uuid.to_b58())?, fx_uuid);
+		assert_eq!(from_b64(&fx_uuid.to_b64())?, fx_uuid);
+		assert_eq!(from_b64url(&fx_uuid.to_b64url())?, fx_uuid);
+		assert_eq!(from_b64url_nopad(&fx_uuid.to_b64url_nopad())?, fx_uuid);
+		assert_eq!(fx_uuid.to_b32(), "01H3Q2MVTEF8NSRF8Y5WX4PQ3D");
+		assert_eq!(fx_uuid.to_b62(), crate::Encoding::B62.encode(&fx_uuid));
+		assert_eq!(fx_uuid.to_b58_sortable().len(), 22);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_uuid_ext_epoch_ms() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_v7 = v7_from_epoch_ms(1_700_000_000_000)?;
+		let fx_v4 = crate::new_v4();
+
+		// -- Exec
+		let epoch_ms = fx_v7.epoch_ms()?;
+		let res_v4 = fx_v4.epoch_ms();
+
+		// -- Check
+		assert_eq!(epoch_ms, 1_700_000_000_000);
+		assert!(matches!(res_v4, Err(Error::FailExtractTimeNoUuidV7(_))));
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,174 @@
+use crate::extra_base32::to_b32;
+use crate::extra_base58::{to_b58, to_b58_sortable};
+use crate::extra_base62::to_b62;
+use crate::extra_base64::{to_b64, to_b64url, to_b64url_nopad};
+use crate::extra_uuid::{v7_max_for_epoch_ms, v7_min_for_epoch_ms};
+use crate::{Error, Result};
+use uuid::Uuid;
+
+/// Inclusive range of UUID version 7 covering a time window in epoch milliseconds.
+///
+/// The bounds are the smallest v7 of `start_ms` and the largest v7 of `end_ms`, so they can be
+/// used directly in range queries (e.g., `WHERE id BETWEEN $min AND $max`) for partition pruning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct V7Range {
+	start_ms: i64,
+	end_ms: i64,
+	min: Uuid,
+	max: Uuid,
+}
+
+/// Constructors
+impl V7Range {
+	/// Creates a range from `start_ms` to `end_ms`, both inclusive.
+	///
+	/// Returns `Error::V7RangeStartAfterEnd` if `start_ms > end_ms`,
+	/// or `Error::V7EpochMsOutOfRange` if a timestamp does not fit in 48 bits.
+	pub fn from_epoch_ms(start_ms: i64, end_ms: i64) -> Result<Self> {
+		if start_ms > end_ms {
+			return Err(Error::V7RangeStartAfterEnd { start_ms, end_ms });
+		}
+
+		Ok(Self {
+			start_ms,
+			end_ms,
+			min: v7_min_for_epoch_ms(start_ms)?,
+			max: v7_max_for_epoch_ms(end_ms)?,
+		})
+	}
+}
+
+/// Getters
+impl V7Range {
+	pub fn start_ms(&self) -> i64 {
+		self.start_ms
+	}
+
+	pub fn end_ms(&self) -> i64 {
+		self.end_ms
+	}
+
+	/// Lower bound (inclusive).
+	pub fn min(&self) -> Uuid {
+		self.min
+	}
+
+	/// Upper bound (inclusive).
+	pub fn max(&self) -> Uuid {
+		self.max
+	}
+
+	/// Returns `true` if `uuid` is within the bounds (inclusive).
+	///
+	/// Note: This is a pure ordering check, so non-v7 UUIDs within the bounds also match.
+	pub fn contains(&self, uuid: &Uuid) -> bool {
+		self.min <= *uuid && *uuid <= self.max
+	}
+}
+
+/// Encoded bounds, as `(min, max)`
+impl V7Range {
+	pub fn bounds_b58(&self) -> (String, String) {
+		(to_b58(&self.min), to_b58(&self.max))
+	}
+
+	pub fn bounds_b58_sortable(&self) -> (String, String) {
+		(to_b58_sortable(&self.min), to_b58_sortable(&self.max))
+	}
+
+	pub fn bounds_b62(&self) -> (String, String) {
+		(to_b62(&self.min), to_b62(&self.max))
+	}
+
+	pub fn bounds_b32(&self) -> (String, String) {
+		(to_b32(&self.min), to_b32(&self.max))
+	}
+
+	pub fn bounds_b64(&self) -> (String, String) {
+		(to_b64(&self.min), to_b64(&self.max))
+	}
+
+	pub fn bounds_b64url(&self) -> (String, String) {
+		(to_b64url(&self.min), to_b64url(&self.max))
+	}
+
+	pub fn bounds_b64url_nopad(&self) -> (String, String) {
+		(to_b64url_nopad(&self.min), to_b64url_nopad(&self.max))
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::{from_b58, from_b64url_nopad, v7_from_epoch_ms};
+
+	#[test]
+	fn test_extra_v7_range_contains_simple() -> Result<()> {
+		// -- Setup & Fixtures
+		let range = V7Range::from_epoch_ms(1_700_000_000_000, 1_700_000_060_000)?;
+		let fx_inside = [
+			v7_from_epoch_ms(1_700_000_000_000)?,
+			v7_from_epoch_ms(1_700_000_030_000)?,
+			v7_from_epoch_ms(1_700_000_060_000)?,
+		];
+		let fx_outside = [
+			v7_from_epoch_ms(1_699_999_999_999)?,
+			v7_from_epoch_ms(1_700_000_060_001)?,
+		];
+
+		// -- Exec & Check
+		for uuid in fx_inside {
+			assert!(range.contains(&uuid), "{uuid} should be in range");
+		}
+		for uuid in fx_outside {
+			assert!(!range.contains(&uuid), "{uuid} should not be in range");
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_v7_range_bounds_encoded_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let range = V7Range::from_epoch_ms(1_700_000_000_000, 1_700_000_000_000)?;
+
+		// -- Exec
+		let (min_b58, max_b58) = range.bounds_b58();
+		let (min_b64u, max_b64u) = range.bounds_b64url_nopad();
+		let (min_sortable, max_sortable) = range.bounds_b58_sortable();
+
+		// -- Check
+		assert_eq!(from_b58(&min_b58)?, range.min());
+		assert_eq!(from_b58(&max_b58)?, range.max());
+		assert_eq!(from_b64url_nopad(&min_b64u)?, range.min());
+		assert_eq!(from_b64url_nopad(&max_b64u)?, range.max());
+		assert!(min_sortable < max_sortable);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_v7_range_from_epoch_ms_err() -> Result<()> {
+		// -- Setup & Fixtures
+		// (no specific setup needed for this test)
+
+		// -- Exec
+		let res_reversed = V7Range::from_epoch_ms(2_000, 1_000);
+		let res_negative = V7Range::from_epoch_ms(-1, 1_000);
+
+		// -- Check
+		assert!(matches!(
+			res_reversed,
+			Err(Error::V7RangeStartAfterEnd {
+				start_ms: 2_000,
+				end_ms: 1_000
+			})
+		));
+		assert!(matches!(res_negative, Err(Error::V7EpochMsOutOfRange { epoch_ms: -1 })));
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,569 @@
+use crate::extra_base32::to_b32;
+use crate::extra_base58::{to_b58, to_b58_sortable};
+use crate::extra_base62::to_b62;
+use crate::extra_base64::{to_b64, to_b64url, to_b64url_nopad};
+use crate::extra_source::{Clock, OsRng, RngSource, SystemClock};
+use crate::extra_uuid::expect_version;
+use crate::{Error, Result};
+use uuid::Uuid;
+
+/// Number of custom bits in a UUID version 8 (128 minus the 4 version and 2 variant bits).
+pub const V8_PAYLOAD_BITS: u8 = 122;
+
+/// Bits of the payload after `custom_a` (12 `custom_b` + 62 `custom_c`).
+const CUSTOM_A_SHIFT: u32 = 74;
+const CUSTOM_C_BITS: u32 = 62;
+const CUSTOM_C_MASK: u128 = (1 << CUSTOM_C_BITS) - 1;
+
+/// Max bits of the timestamp, shard, and sequence fields (their values are `u64`/`i64`).
+const VALUE_MAX_BITS: u8 = 64;
+
+// region:    --- V8Field
+
+/// A bit field of a `V8Layout`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum V8Field {
+	/// Epoch milliseconds (a 48-bit timestamp first gives the same time ordering as v7).
+	Timestamp,
+	/// Shard, node, or region identifier used for routing.
+	Shard,
+	/// Caller-provided sequence (e.g., a per-shard counter).
+	Sequence,
+	/// Random bits (the layout bits left unassigned are random as well).
+	Random,
+}
+
+/// Field values of a UUID version 8, as laid out by a `V8Layout`.
+///
+/// A field that is not in the layout must be zero (a value out of its 0 bits).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct V8Fields {
+	pub epoch_ms: i64,
+	pub shard: u64,
+	pub sequence: u64,
+	/// All random bits of the layout, concatenated in layout order.
+	pub random: u128,
+}
+
+// endregion: --- V8Field
+
+// region:    --- V8LayoutBuilder
+
+/// Builder of a `V8Layout`, fields are laid out in call order from the most significant bit.
+///
+/// ```ignore
+/// let layout = V8Layout::builder().timestamp_ms(48).shard(10).sequence(12).build()?;
+/// let id_b58 = layout.new_b58(shard_id, 0)?;
+/// let shard_id = layout.shard(&from_b58(&id_b58)?)?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct V8LayoutBuilder {
+	fields: Vec<(V8Field, u8)>,
+}
+
+/// Fields
+impl V8LayoutBuilder {
+	/// Adds the epoch milliseconds timestamp field (1 to 64 bits).
+	///
+	/// Generated ids keep the low `bits` of the time, so a field narrower than about 41 bits wraps
+	/// (e.g., every ~49.7 days for 32 bits, so it only orders ids within that window).
+	pub fn timestamp_ms(self, bits: u8) -> Self {
+		self.field(V8Field::Timestamp, bits)
+	}
+
+	/// Adds the shard field (1 to 64 bits).
+	pub fn shard(self, bits: u8) -> Self {
+		self.field(V8Field::Shard, bits)
+	}
+
+	/// Adds the sequence field (1 to 64 bits).
+	pub fn sequence(self, bits: u8) -> Self {
+		self.field(V8Field::Sequence, bits)
+	}
+
+	/// Adds random bits (1 to 122 bits), can be called more than once.
+	pub fn random(self, bits: u8) -> Self {
+		self.field(V8Field::Random, bits)
+	}
+
+	fn field(mut self, field: V8Field, bits: u8) -> Self {
+		self.fields.push((field, bits));
+		self
+	}
+}
+
+/// Build
+impl V8LayoutBuilder {
+	/// Validates the fields and builds the layout, the remaining bits (if any) being random.
+	///
+	/// Returns `Error::V8FieldBitsOutOfRange`, `Error::V8DuplicateField`,
+	/// or `Error::V8LayoutTooManyBits` if the fields exceed the 122 custom bits.
+	pub fn build(self) -> Result<V8Layout> {
+		let mut slots = Vec::with_capacity(self.fields.len() + 1);
+		let mut offset: u32 = 0;
+
+		for (field, bits) in self.fields {
+			let max_bits = if field == V8Field::Random {
+				V8_PAYLOAD_BITS
+			} else {
+				VALUE_MAX_BITS
+			};
+			if bits == 0 || bits > max_bits {
+				return Err(Error::V8FieldBitsOutOfRange { field, bits });
+			}
+			if field != V8Field::Random && slots.iter().any(|slot: &Slot| slot.field == field) {
+				return Err(Error::V8DuplicateField { field });
+			}
+			slots.push(Slot { field, offset, bits });
+			offset += bits as u32;
+		}
+
+		if offset > V8_PAYLOAD_BITS as u32 {
+			return Err(Error::V8LayoutTooManyBits { bits: offset });
+		}
+		let rest_bits = V8_PAYLOAD_BITS - offset as u8;
+		if rest_bits > 0 {
+			slots.push(Slot {
+				field: V8Field::Random,
+				offset,
+				bits: rest_bits,
+			});
+		}
+
+		Ok(V8Layout { slots })
+	}
+}
+
+// endregion: --- V8LayoutBuilder
+
+// region:    --- V8Layout
+
+/// Bit layout of custom UUID version 8 (RFC 9562, Section 5.8), e.g., a timestamp and a shard id
+/// so requests can be routed from the id alone.
+///
+/// The 122 custom bits are seen as one contiguous payload (skipping the version and variant bits),
+/// so a 48-bit timestamp first sits exactly where the v7 timestamp does.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct V8Layout {
+	slots: Vec<Slot>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Slot {
+	field: V8Field,
+	/// Offset from the most significant bit of the payload.
+	offset: u32,
+	bits: u8,
+}
+
+/// Constructors & Getters
+impl V8Layout {
+	pub fn builder() -> V8LayoutBuilder {
+		V8LayoutBuilder::default()
+	}
+
+	/// Number of bits of `field` in this layout (0 if absent, the total for `V8Field::Random`).
+	pub fn bits(&self, field: V8Field) -> u8 {
+		self.slots.iter().filter(|slot| slot.field == field).map(|slot| slot.bits).sum()
+	}
+}
+
+/// Generation
+impl V8Layout {
+	/// Builds the UUID version 8 holding `fields`.
+	///
+	/// Returns `Error::V8FieldOutOfRange` if a value does not fit in its field
+	/// (or is not zero for a field absent from the layout).
+	pub fn to_uuid(&self, fields: &V8Fields) -> Result<Uuid> {
+		let V8Fields {
+			epoch_ms,
+			shard,
+			sequence,
+			random,
+		} = *fields;
+
+		if epoch_ms < 0 {
+			return Err(self.out_of_range(V8Field::Timestamp));
+		}
+		let values = [
+			(V8Field::Timestamp, epoch_ms as u128),
+			(V8Field::Shard, shard as u128),
+			(V8Field::Sequence, sequence as u128),
+			(V8Field::Random, random),
+		];
+		for (field, value) in values {
+			if value & !mask(self.bits(field)) != 0 {
+				return Err(self.out_of_range(field));
+			}
+		}
+
+		let mut random_bits_left = self.bits(V8Field::Random);
+		let mut payload: u128 = 0;
+		for slot in self.slots.iter() {
+			let value = match slot.field {
+				V8Field::Timestamp => epoch_ms as u128,
+				V8Field::Shard => shard as u128,
+				V8Field::Sequence => sequence as u128,
+				V8Field::Random => {
+					random_bits_left -= slot.bits;
+					(random >> random_bits_left) & mask(slot.bits)
+				}
+			};
+			payload |= value << slot.shift();
+		}
+
+		Ok(build_v8(payload))
+	}
+
+	/// Generates a new UUID version 8 with the now time, `shard`, `sequence`, and OS random bits.
+	///
+	/// The time is wrapped to the timestamp field width (see `V8LayoutBuilder::timestamp_ms`).
+	pub fn new_uuid(&self, shard: u64, sequence: u64) -> Result<Uuid> {
+		self.new_uuid_with(&mut SystemClock, &mut OsRng, shard, sequence)
+	}
+
+	/// Same as `new_uuid`, with a custom clock and random source (e.g., for reproducible ids).
+	pub fn new_uuid_with(
+		&self,
+		clock: &mut impl Clock,
+		rng: &mut impl RngSource,
+		shard: u64,
+		sequence: u64,
+	) -> Result<Uuid> {
+		// Wrapped to the field width (zero bits when the layout has no timestamp).
+		let epoch_ms = (clock.now().as_millis() & mask(self.bits(V8Field::Timestamp))) as i64;
+		let mut bytes = [0u8; 16];
+		rng.fill_bytes(&mut bytes);
+		let random = u128::from_be_bytes(bytes) & mask(self.bits(V8Field::Random));
+
+		self.to_uuid(&V8Fields {
+			epoch_ms,
+			shard,
+			sequence,
+			random,
+		})
+	}
+
+	fn out_of_range(&self, field: V8Field) -> Error {
+		Error::V8FieldOutOfRange {
+			field,
+			bits: self.bits(field),
+		}
+	}
+}
+
+/// Generation Encoded
+impl V8Layout {
+	/// Same as `new_uuid`, encoded using Base58.
+	pub fn new_b58(&self, shard: u64, sequence: u64) -> Result<String> {
+		Ok(to_b58(&self.new_uuid(shard, sequence)?))
+	}
+
+	/// Same as `new_uuid`, encoded using fixed-width, order-preserving Base58.
+	pub fn new_b58_sortable(&self, shard: u64, sequence: u64) -> Result<String> {
+		Ok(to_b58_sortable(&self.new_uuid(shard, sequence)?))
+	}
+
+	/// Same as `new_uuid`, encoded using Base62.
+	pub fn new_b62(&self, shard: u64, sequence: u64) -> Result<String> {
+		Ok(to_b62(&self.new_uuid(shard, sequence)?))
+	}
+
+	/// Same as `new_uuid`, encoded using Crockford Base32.
+	pub fn new_b32(&self, shard: u64, sequence: u64) -> Result<String> {
+		Ok(to_b32(&self.new_uuid(shard, sequence)?))
+	}
+
+	/// Same as `new_uuid`, encoded using standard Base64.
+	pub fn new_b64(&self, shard: u64, sequence: u64) -> Result<String> {
+		Ok(to_b64(&self.new_uuid(shard, sequence)?))
+	}
+
+	/// Same as `new_uuid`, encoded using URL-safe Base64.
+	pub fn new_b64url(&self, shard: u64, sequence: u64) -> Result<String> {
+		Ok(to_b64url(&self.new_uuid(shard, sequence)?))
+	}
+
+	/// Same as `new_uuid`, encoded using URL-safe Base64 without padding.
+	pub fn new_b64url_nopad(&self, shard: u64, sequence: u64) -> Result<String> {
+		Ok(to_b64url_nopad(&self.new_uuid(shard, sequence)?))
+	}
+}
+
+/// Extractors
+impl V8Layout {
+	/// Returns all the field values of a UUID version 8 (zero for fields absent from the layout).
+	///
+	/// Returns `Error::UnexpectedVersion` if the UUID is not a version 8.
+	pub fn fields(&self, uuid: &Uuid) -> Result<V8Fields> {
+		expect_version(uuid, 8)?;
+		let payload = v8_payload(uuid);
+
+		let mut fields = V8Fields::default();
+		for slot in self.slots.iter() {
+			let value = (payload >> slot.shift()) & mask(slot.bits);
+			match slot.field {
+				V8Field::Timestamp => fields.epoch_ms = value as i64,
+				V8Field::Shard => fields.shard = value as u64,
+				V8Field::Sequence => fields.sequence = value as u64,
+				V8Field::Random => fields.random = (fields.random << slot.bits) | value,
+			}
+		}
+
+		Ok(fields)
+	}
+
+	/// Returns the epoch time in milliseconds of a UUID version 8 (like `to_time_epoch_ms` for v7).
+	///
+	/// For a timestamp field narrower than the current time, this is the wrapped value (the low bits).
+	///
+	/// Returns `Error::UnexpectedVersion` if the UUID is not a version 8,
+	/// or `Error::V8FieldNotInLayout` if the layout has no timestamp.
+	pub fn epoch_ms(&self, uuid: &Uuid) -> Result<i64> {
+		Ok(self.field_value(uuid, V8Field::Timestamp)? as i64)
+	}
+
+	/// Returns the shard of a UUID version 8.
+	///
+	/// Returns `Error::UnexpectedVersion` if the UUID is not a version 8,
+	/// or `Error::V8FieldNotInLayout` if the layout has no shard.
+	pub fn shard(&self, uuid: &Uuid) -> Result<u64> {
+		Ok(self.field_value(uuid, V8Field::Shard)? as u64)
+	}
+
+	/// Returns the sequence of a UUID version 8.
+	///
+	/// Returns `Error::UnexpectedVersion` if the UUID is not a version 8,
+	/// or `Error::V8FieldNotInLayout` if the layout has no sequence.
+	pub fn sequence(&self, uuid: &Uuid) -> Result<u64> {
+		Ok(self.field_value(uuid, V8Field::Sequence)? as u64)
+	}
+
+	fn field_value(&self, uuid: &Uuid, field: V8Field) -> Result<u128> {
+		expect_version(uuid, 8)?;
+		let slot = self
+			.slots
+			.iter()
+			.find(|slot| slot.field == field)
+			.ok_or(Error::V8FieldNotInLayout { field })?;
+
+		Ok((v8_payload(uuid) >> slot.shift()) & mask(slot.bits))
+	}
+}
+
+impl Slot {
+	/// Shift of the field value within the 122-bit payload.
+	fn shift(&self) -> u32 {
+		V8_PAYLOAD_BITS as u32 - self.offset - self.bits as u32
+	}
+}
+
+// endregion: --- V8Layout
+
+// region:    --- Support
+
+fn build_v8(payload: u128) -> Uuid {
+	let custom_a = (payload >> CUSTOM_A_SHIFT) << 80;
+	let ver = 0x8 << 76;
+	let custom_b = ((payload >> CUSTOM_C_BITS) & 0xFFF) << 64;
+	let var = 0b10 << 62;
+	let custom_c = payload & CUSTOM_C_MASK;
+	Uuid::from_u128(custom_a | ver | custom_b | var | custom_c)
+}
+
+/// Returns the 122 custom bits of a UUID version 8 (the caller checks the version).
+fn v8_payload(uuid: &Uuid) -> u128 {
+	let value = uuid.as_u128();
+	let custom_a = value >> 80;
+	let custom_b = (value >> 64) & 0xFFF;
+	let custom_c = value & CUSTOM_C_MASK;
+	(custom_a << CUSTOM_A_SHIFT) | (custom_b << CUSTOM_C_BITS) | custom_c
+}
+
+fn mask(bits: u8) -> u128 {
+	if bits >= 128 { u128::MAX } else { (1 << bits) - 1 }
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::extra_source::{FixedClock, SeededRng};
+	use crate::{from_b58, from_b64url_nopad, new_v7};
+
+	fn fx_layout() -> crate::Result<V8Layout> {
+		V8Layout::builder().timestamp_ms(48).shard(10).sequence(12).build()
+	}
+
+	#[test]
+	fn test_extra_v8_to_uuid_fields_roundtrip() -> Result<()> {
+		// -- Setup & Fixtures
+		let layout = fx_layout()?;
+		let fx_fields = V8Fields {
+			epoch_ms: 1_700_000_000_000,
+			shard: 1023,
+			sequence: 42,
+			random: (1 << 52) - 1,
+		};
+
+		// -- Exec
+		let uuid = layout.to_uuid(&fx_fields)?;
+
+		// -- Check
+		assert_eq!(uuid.get_version_num(), 8);
+		assert_eq!(uuid.get_variant(), uuid::Variant::RFC4122);
+		assert_eq!(layout.bits(V8Field::Random), 52);
+		assert_eq!(layout.fields(&uuid)?, fx_fields);
+		assert_eq!(layout.epoch_ms(&uuid)?, 1_700_000_000_000);
+		assert_eq!(layout.shard(&uuid)?, 1023);
+		assert_eq!(layout.sequence(&uuid)?, 42);
+		// Same timestamp position as v7.
+		assert_eq!((uuid.as_u128() >> 80) as i64, 1_700_000_000_000);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_v8_new_uuid_with_reproducible() -> Result<()> {
+		// -- Setup & Fixtures
+		let layout = V8Layout::builder().shard(10).timestamp_ms(48).random(8).sequence(12).build()?;
+		let new_uuid = || {
+			let (mut clock, mut rng) = (FixedClock::from_epoch_ms(1_700_000_000_000), SeededRng::new(7));
+			layout.new_uuid_with(&mut clock, &mut rng, 5, 9)
+		};
+
+		// -- Exec
+		let uuid_1 = new_uuid()?;
+		let uuid_2 = new_uuid()?;
+
+		// -- Check
+		assert_eq!(uuid_1, uuid_2);
+		assert_eq!(layout.shard(&uuid_1)?, 5);
+		assert_eq!(layout.epoch_ms(&uuid_1)?, 1_700_000_000_000);
+		assert_eq!(layout.sequence(&uuid_1)?, 9);
+		assert_eq!(layout.bits(V8Field::Random), 52);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_v8_new_uuid_narrow_timestamp_wraps() -> Result<()> {
+		// -- Setup & Fixtures
+		let layout = V8Layout::builder().timestamp_ms(32).shard(16).build()?;
+		let fx_epoch_ms: i64 = 1_700_000_000_000;
+		let mut clock = FixedClock::from_epoch_ms(fx_epoch_ms as u64);
+
+		// -- Exec
+		let uuid = layout.new_uuid_with(&mut clock, &mut SeededRng::new(7), 3, 0)?;
+		let uuid_now = layout.new_uuid(3, 0)?;
+
+		// -- Check
+		assert_eq!(layout.epoch_ms(&uuid)?, fx_epoch_ms & 0xFFFF_FFFF);
+		assert_eq!(layout.shard(&uuid)?, 3);
+		assert_eq!(layout.shard(&uuid_now)?, 3);
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_v8_new_encoded_roundtrip() -> Result<()> {
+		// -- Setup & Fixtures
+		let layout = fx_layout()?;
+
+		// -- Exec
+		let id_b58 = layout.new_b58(7, 1)?;
+		let id_b64u = layout.new_b64url_nopad(8, 2)?;
+
+		// -- Check
+		let uuid = from_b58(&id_b58)?;
+		assert_eq!((layout.shard(&uuid)?, layout.sequence(&uuid)?), (7, 1));
+		let uuid = from_b64url_nopad(&id_b64u)?;
+		assert_eq!((layout.shard(&uuid)?, layout.sequence(&uuid)?), (8, 2));
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_v8_build_err() -> Result<()> {
+		// -- Exec
+		let res_too_many = V8Layout::builder().timestamp_ms(64).shard(64).build();
+		let res_duplicate = V8Layout::builder().shard(10).shard(4).build();
+		let res_zero = V8Layout::builder().sequence(0).build();
+
+		// -- Check
+		assert!(matches!(res_too_many, Err(Error::V8LayoutTooManyBits { bits: 128 })));
+		assert!(matches!(
+			res_duplicate,
+			Err(Error::V8DuplicateField { field: V8Field::Shard })
+		));
+		assert!(matches!(
+			res_zero,
+			Err(Error::V8FieldBitsOutOfRange {
+				field: V8Field::Sequence,
+				bits: 0
+			})
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_v8_to_uuid_err_out_of_range() -> Result<()> {
+		// -- Setup & Fixtures
+		let layout = fx_layout()?;
+		let layout_no_seq = V8Layout::builder().shard(10).build()?;
+
+		// -- Exec
+		let res_shard = layout.to_uuid(&V8Fields {
+			shard: 1024,
+			..Default::default()
+		});
+		let res_absent = layout_no_seq.to_uuid(&V8Fields {
+			sequence: 1,
+			..Default::default()
+		});
+
+		// -- Check
+		assert!(matches!(
+			res_shard,
+			Err(Error::V8FieldOutOfRange {
+				field: V8Field::Shard,
+				bits: 10
+			})
+		));
+		assert!(matches!(
+			res_absent,
+			Err(Error::V8FieldOutOfRange {
+				field: V8Field::Sequence,
+				bits: 0
+			})
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn test_extra_v8_extract_err() -> Result<()> {
+		// -- Setup & Fixtures
+		let layout = V8Layout::builder().shard(10).build()?;
+		let uuid = layout.new_uuid(3, 0)?;
+
+		// -- Exec
+		let res_version = layout.shard(&new_v7());
+		let res_absent = layout.epoch_ms(&uuid);
+
+		// -- Check
+		assert!(matches!(
+			res_version,
+			Err(Error::UnexpectedVersion { expected: 8, actual: 7 })
+		));
+		assert!(matches!(
+			res_absent,
+			Err(Error::V8FieldNotInLayout {
+				field: V8Field::Timestamp
+			})
+		));
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
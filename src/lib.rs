@@ -1,15 +1,60 @@
 // region:    --- Modules
 
 mod support;
+#[cfg(feature = "mnemonic")]
+mod support_bip39;
 
 mod error;
+mod extra_base32;
 mod extra_base58;
+mod extra_base62;
 mod extra_base64;
+mod extra_checked;
+mod extra_codec;
+mod extra_encoded;
+mod extra_generator;
+mod extra_id;
+mod extra_multibase;
+mod extra_name;
+mod extra_parse;
+mod extra_prefixed;
+mod extra_pronounceable;
+mod extra_source;
+mod extra_typeid;
 mod extra_uuid;
+mod extra_uuid_ext;
+mod extra_v7_range;
+mod extra_v8;
 
 pub use error::{Error, Result};
+pub use extra_base32::*;
 pub use extra_base58::*;
+pub use extra_base62::*;
 pub use extra_base64::*;
+pub use extra_checked::*;
+pub use extra_codec::*;
+pub use extra_encoded::*;
+pub use extra_generator::*;
+pub use extra_id::*;
+pub use extra_multibase::*;
+pub use extra_name::*;
+pub use extra_parse::*;
+pub use extra_prefixed::*;
+pub use extra_pronounceable::*;
+pub use extra_source::*;
+pub use extra_typeid::*;
 pub use extra_uuid::*;
+pub use extra_uuid_ext::*;
+pub use extra_v7_range::*;
+pub use extra_v8::*;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "obfuscate")]
+pub mod obfuscate;
+
+#[cfg(feature = "signed")]
+pub mod signed;
 
 // endregion: --- Modules
@@ -0,0 +1,157 @@
+//! Keyed obfuscation of UUIDs for public ids (requires the `obfuscate` feature).
+//!
+//! A v7 UUID leaks its creation time (and, over many ids, the creation rate). `PublicIdCodec` applies
+//! AES-128 to the 16 bytes (a single-block, keyed permutation), so the internal, time-ordered UUID
+//! is kept as the database key, while the public id reveals nothing without the key.
+//!
+//! ```ignore
+//! let codec = PublicIdCodec::new(&key);          // 16-byte secret key, e.g., from config
+//! let public_id = codec.encode_b58(&order.id);   // opaque, stable for a given id and key
+//! let order_id = codec.decode_b58(&public_id)?;  // the original v7
+//! ```
+//!
+//! Notes:
+//! - The mapping is deterministic, the same UUID always gives the same public id (no IV or nonce).
+//! - This is obfuscation, not authentication: any 128-bit value decodes to some UUID.
+//!   Check that the revealed UUID exists before using it.
+//! - Changing the key changes all public ids.
+
+use crate::{Result, from_b58, from_b64url_nopad, to_b58, to_b64url_nopad};
+use aes::Aes128;
+use aes::cipher::{BlockDecrypt, BlockEncrypt, KeyInit};
+use core::fmt;
+use uuid::Uuid;
+
+/// Codec between internal UUIDs and opaque public ids, keyed with a 128-bit secret.
+#[derive(Clone)]
+pub struct PublicIdCodec {
+	cipher: Aes128,
+}
+
+/// Constructors
+impl PublicIdCodec {
+	pub fn new(key: &[u8; 16]) -> Self {
+		Self {
+			cipher: Aes128::new(key.into()),
+		}
+	}
+}
+
+/// Raw Uuid
+impl PublicIdCodec {
+	/// Returns the opaque UUID of `uuid` (the AES-128 encryption of its 16 bytes).
+	///
+	/// Note: The result is not an RFC 9562 UUID (the version and variant bits are scrambled too).
+	pub fn obfuscate(&self, uuid: &Uuid) -> Uuid {
+		let mut block = (*uuid.as_bytes()).into();
+		self.cipher.encrypt_block(&mut block);
+		Uuid::from_bytes(block.into())
+	}
+
+	/// Returns the internal UUID of an opaque UUID (inverse of `obfuscate`).
+	pub fn reveal(&self, obfuscated: &Uuid) -> Uuid {
+		let mut block = (*obfuscated.as_bytes()).into();
+		self.cipher.decrypt_block(&mut block);
+		Uuid::from_bytes(block.into())
+	}
+}
+
+/// Encoded
+impl PublicIdCodec {
+	/// Obfuscates `uuid` and encodes it using Base58.
+	pub fn encode_b58(&self, uuid: &Uuid) -> String {
+		to_b58(&self.obfuscate(uuid))
+	}
+
+	/// Decodes a Base58 public id and returns the internal UUID.
+	pub fn decode_b58(&self, s: &str) -> Result<Uuid> {
+		Ok(self.reveal(&from_b58(s)?))
+	}
+
+	/// Obfuscates `uuid` and encodes it using URL-safe Base64 without padding.
+	pub fn encode_b64url_nopad(&self, uuid: &Uuid) -> String {
+		to_b64url_nopad(&self.obfuscate(uuid))
+	}
+
+	/// Decodes a URL-safe Base64 (no padding) public id and returns the internal UUID.
+	pub fn decode_b64url_nopad(&self, s: &str) -> Result<Uuid> {
+		Ok(self.reveal(&from_b64url_nopad(s)?))
+	}
+}
+
+// Does not print the key schedule.
+impl fmt::Debug for PublicIdCodec {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("PublicIdCodec").finish_non_exhaustive()
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::{to_time_epoch_ms, v7_from_epoch_ms};
+
+	const FX_KEY: [u8; 16] = *b"0123456789abcdef";
+
+	#[test]
+	fn test_obfuscate_fips_197_vector() -> Result<()> {
+		// -- Setup & Fixtures
+		// FIPS-197, Appendix C.1 (AES-128), pins the permutation so public ids stay stable.
+		let fx_key: [u8; 16] = core::array::from_fn(|i| i as u8);
+		let fx_plain = Uuid::parse_str("00112233-4455-6677-8899-aabbccddeeff")?;
+		let codec = PublicIdCodec::new(&fx_key);
+
+		// -- Exec
+		let obfuscated = codec.obfuscate(&fx_plain);
+
+		// -- Check
+		assert_eq!(obfuscated, Uuid::parse_str("69c4e0d8-6a7b-0430-d8cd-b78070b4c55a")?);
+		assert_eq!(codec.reveal(&obfuscated), fx_plain);
+		Ok(())
+	}
+
+	#[test]
+	fn test_obfuscate_encode_decode_roundtrip() -> Result<()> {
+		// -- Setup & Fixtures
+		let codec = PublicIdCodec::new(&FX_KEY);
+		let fx_uuid = crate::new_v7();
+
+		// -- Exec
+		let id_b58 = codec.encode_b58(&fx_uuid);
+		let id_b64u = codec.encode_b64url_nopad(&fx_uuid);
+
+		// -- Check
+		assert_eq!(codec.decode_b58(&id_b58)?, fx_uuid);
+		assert_eq!(codec.decode_b64url_nopad(&id_b64u)?, fx_uuid);
+		assert_ne!(from_b58(&id_b58)?, fx_uuid);
+		assert_eq!(codec.encode_b58(&fx_uuid), id_b58, "Should be deterministic");
+		Ok(())
+	}
+
+	#[test]
+	fn test_obfuscate_hides_v7_timestamp() -> Result<()> {
+		// -- Setup & Fixtures
+		let codec = PublicIdCodec::new(&FX_KEY);
+		let other_codec = PublicIdCodec::new(b"fedcba9876543210");
+		let fx_uuid_1 = v7_from_epoch_ms(1_700_000_000_000)?;
+		let fx_uuid_2 = v7_from_epoch_ms(1_700_000_000_000)?;
+
+		// -- Exec
+		let public_1 = codec.obfuscate(&fx_uuid_1);
+		let public_2 = codec.obfuscate(&fx_uuid_2);
+
+		// -- Check
+		// Same millisecond, but no shared timestamp prefix once obfuscated.
+		assert_ne!(public_1.as_bytes()[..6], public_2.as_bytes()[..6]);
+		assert_ne!(to_time_epoch_ms(&public_1).ok(), Some(1_700_000_000_000));
+		assert_ne!(other_codec.reveal(&public_1), fx_uuid_1);
+		assert_eq!(format!("{codec:?}"), "PublicIdCodec { .. }");
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,433 @@
+//! Serde `with`-modules to pick the string encoding of a `Uuid` field (requires the `serde` feature).
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Order {
+//!     #[serde(with = "uuid_extra::serde::b58")]
+//!     id: Uuid,
+//!     #[serde(with = "uuid_extra::serde::b64url_nopad::option", default)]
+//!     parent_id: Option<Uuid>,
+//!     #[serde(with = "uuid_extra::serde::b58::vec")]
+//!     item_ids: Vec<Uuid>,
+//!     // Serialized as Base58, but deserialized from Base58 or any `parse_any` encoding.
+//!     #[serde(with = "uuid_extra::serde::b58::lenient")]
+//!     legacy_id: Uuid,
+//! }
+//! ```
+//!
+//! Each module has `option` and `vec` variants, and a `lenient` variant (with its own `option` and `vec`).
+//!
+//! Human-readable formats (e.g., JSON) use the encoded string, while binary formats use the 16 raw bytes.
+
+use crate::{Codec, Id, parse_any};
+use ::serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+use core::fmt;
+use core::marker::PhantomData;
+use uuid::Uuid;
+
+// region:    --- With Modules
+
+/// Generates the `serialize`/`deserialize` functions, with the `option` and `vec` variants.
+macro_rules! impl_with_fns {
+	($codec:ty, $lenient:literal) => {
+		use ::serde::{Deserializer, Serializer};
+		use uuid::Uuid;
+
+		pub fn serialize<S: Serializer>(uuid: &Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+			$crate::serde::serialize_uuid::<$codec, S>(uuid, serializer)
+		}
+
+		pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+			$crate::serde::deserialize_uuid::<$codec, $lenient, D>(deserializer)
+		}
+
+		/// For `Option<Uuid>` fields (add `#[serde(default)]` to accept a missing field).
+		pub mod option {
+			use ::serde::{Deserializer, Serializer};
+			use uuid::Uuid;
+
+			pub fn serialize<S: Serializer>(uuid: &Option<Uuid>, serializer: S) -> Result<S::Ok, S::Error> {
+				$crate::serde::serialize_option::<$codec, S>(uuid, serializer)
+			}
+
+			pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Uuid>, D::Error> {
+				$crate::serde::deserialize_option::<$codec, $lenient, D>(deserializer)
+			}
+		}
+
+		/// For `Vec<Uuid>` fields.
+		pub mod vec {
+			use ::serde::{Deserializer, Serializer};
+			use uuid::Uuid;
+
+			pub fn serialize<S: Serializer>(uuids: &[Uuid], serializer: S) -> Result<S::Ok, S::Error> {
+				$crate::serde::serialize_vec::<$codec, S>(uuids, serializer)
+			}
+
+			pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Uuid>, D::Error> {
+				$crate::serde::deserialize_vec::<$codec, $lenient, D>(deserializer)
+			}
+		}
+	};
+}
+
+/// Generates a `with`-module for a `Codec` marker, with its `lenient` variant.
+macro_rules! impl_with_module {
+	($(#[$meta:meta])* $name:ident, $codec:ty) => {
+		$(#[$meta])*
+		pub mod $name {
+			impl_with_fns!($codec, false);
+
+			/// Same encoding on serialize, but deserialize also accepts the `parse_any` encodings.
+			///
+			/// The module encoding is tried first, then `parse_any` (`Encoding::AUTO_DETECT`, which fails on
+			/// ambiguous strings).
+			pub mod lenient {
+				impl_with_fns!($codec, true);
+			}
+		}
+	};
+}
+
+impl_with_module!(
+	/// Base58 (see `new_v7_b58`).
+	b58,
+	crate::B58
+);
+impl_with_module!(
+	/// Fixed-width, order-preserving Base58 (see `new_v7_b58_sortable`).
+	b58_sortable,
+	crate::B58Sortable
+);
+impl_with_module!(
+	/// Base62 (see `new_v7_b62`).
+	b62,
+	crate::B62
+);
+impl_with_module!(
+	/// Crockford Base32 (see `new_v7_b32`).
+	b32,
+	crate::B32
+);
+impl_with_module!(
+	/// Standard Base64 (see `new_v7_b64`).
+	b64,
+	crate::B64
+);
+impl_with_module!(
+	/// URL-safe Base64 (see `new_v7_b64url`).
+	b64url,
+	crate::B64Url
+);
+impl_with_module!(
+	/// URL-safe Base64 without padding (see `new_v7_b64url_nopad`).
+	b64url_nopad,
+	crate::B64UrlNoPad
+);
+impl_with_module!(
+	/// Canonical hyphenated hex.
+	hyphenated,
+	crate::Hyphenated
+);
+
+// endregion: --- With Modules
+
+// region:    --- Id
+
+impl<Tag, Enc: Codec> Serialize for Id<Tag, Enc> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_uuid::<Enc, S>(self.as_uuid(), serializer)
+	}
+}
+
+impl<'de, Tag, Enc: Codec> Deserialize<'de> for Id<Tag, Enc> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_uuid::<Enc, false, D>(deserializer).map(Id::from_uuid)
+	}
+}
+
+// endregion: --- Id
+
+// region:    --- Support
+
+fn serialize_uuid<C: Codec, S: Serializer>(uuid: &Uuid, serializer: S) -> Result<S::Ok, S::Error> {
+	if serializer.is_human_readable() {
+		serializer.serialize_str(&C::encode(uuid))
+	} else {
+		serializer.serialize_bytes(uuid.as_bytes())
+	}
+}
+
+fn deserialize_uuid<'de, C: Codec, const LENIENT: bool, D: Deserializer<'de>>(
+	deserializer: D,
+) -> Result<Uuid, D::Error> {
+	let visitor = UuidVisitor::<C, LENIENT>(PhantomData);
+	if deserializer.is_human_readable() {
+		deserializer.deserialize_str(visitor)
+	} else {
+		deserializer.deserialize_bytes(visitor)
+	}
+}
+
+fn serialize_option<C: Codec, S: Serializer>(uuid: &Option<Uuid>, serializer: S) -> Result<S::Ok, S::Error> {
+	match uuid {
+		Some(uuid) => serializer.serialize_some(&UuidSer::<C>(uuid, PhantomData)),
+		None => serializer.serialize_none(),
+	}
+}
+
+fn deserialize_option<'de, C: Codec, const LENIENT: bool, D: Deserializer<'de>>(
+	deserializer: D,
+) -> Result<Option<Uuid>, D::Error> {
+	let uuid = Option::<UuidDe<C, LENIENT>>::deserialize(deserializer)?;
+	Ok(uuid.map(|de| de.0))
+}
+
+fn serialize_vec<C: Codec, S: Serializer>(uuids: &[Uuid], serializer: S) -> Result<S::Ok, S::Error> {
+	serializer.collect_seq(uuids.iter().map(|uuid| UuidSer::<C>(uuid, PhantomData)))
+}
+
+fn deserialize_vec<'de, C: Codec, const LENIENT: bool, D: Deserializer<'de>>(
+	deserializer: D,
+) -> Result<Vec<Uuid>, D::Error> {
+	let uuids = Vec::<UuidDe<C, LENIENT>>::deserialize(deserializer)?;
+	Ok(uuids.into_iter().map(|de| de.0).collect())
+}
+
+/// Serializable reference, for the `option` and `vec` elements.
+struct UuidSer<'a, C>(&'a Uuid, PhantomData<C>);
+
+impl<C: Codec> Serialize for UuidSer<'_, C> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize_uuid::<C, S>(self.0, serializer)
+	}
+}
+
+/// Deserializable value, for the `option` and `vec` elements.
+struct UuidDe<C, const LENIENT: bool>(Uuid, PhantomData<C>);
+
+impl<'de, C: Codec, const LENIENT: bool> Deserialize<'de> for UuidDe<C, LENIENT> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserialize_uuid::<C, LENIENT, D>(deserializer).map(|uuid| UuidDe(uuid, PhantomData))
+	}
+}
+
+struct UuidVisitor<C, const LENIENT: bool>(PhantomData<C>);
+
+impl<'de, C: Codec, const LENIENT: bool> Visitor<'de> for UuidVisitor<C, LENIENT> {
+	type Value = Uuid;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if LENIENT {
+			write!(
+				f,
+				"a {} UUID string (or another usual encoding), or 16 bytes",
+				C::ENCODING
+			)
+		} else {
+			write!(f, "a {} UUID string, or 16 bytes", C::ENCODING)
+		}
+	}
+
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<Uuid, E> {
+		let res = match C::decode(v) {
+			Ok(uuid) => Ok(uuid),
+			Err(_) if LENIENT => parse_any(v).map(|(uuid, _)| uuid),
+			Err(err) => Err(err),
+		};
+		res.map_err(E::custom)
+	}
+
+	fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Uuid, E> {
+		Uuid::from_slice(v).map_err(|_| E::invalid_length(v.len(), &self))
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Uuid, A::Error> {
+		let mut bytes = [0u8; 16];
+		for (i, byte) in bytes.iter_mut().enumerate() {
+			*byte = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+		}
+		if seq.next_element::<u8>()?.is_some() {
+			return Err(de::Error::invalid_length(17, &self));
+		}
+		Ok(Uuid::from_bytes(bytes))
+	}
+}
+
+// endregion: --- Support
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::{B64UrlNoPad, to_b58, to_b64url_nopad};
+	use ::serde::{Deserialize, Serialize};
+	use serde_json::json;
+	use serde_test::{Configure, Token, assert_tokens};
+
+	const FX_UUID: Uuid = Uuid::from_u128(0x0188ee2a_6f4e_7a2b_9c3d_1e2f3a4b5c6d);
+	const FX_BYTES: [u8; 16] = FX_UUID.into_bytes();
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct FxOrder {
+		#[serde(with = "crate::serde::b58")]
+		id: Uuid,
+		#[serde(with = "crate::serde::b64url_nopad::option", default)]
+		parent_id: Option<Uuid>,
+		#[serde(with = "crate::serde::b58::vec")]
+		item_ids: Vec<Uuid>,
+	}
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct FxLenient {
+		#[serde(with = "crate::serde::b58::lenient")]
+		id: Uuid,
+	}
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct FxLenientB32 {
+		#[serde(with = "crate::serde::b32::lenient")]
+		id: Uuid,
+	}
+
+	#[test]
+	fn test_serde_json_roundtrip() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_order = FxOrder {
+			id: FX_UUID,
+			parent_id: Some(FX_UUID),
+			item_ids: vec![FX_UUID, Uuid::nil()],
+		};
+
+		// -- Exec
+		let value = serde_json::to_value(&fx_order)?;
+		let order: FxOrder = serde_json::from_value(value.clone())?;
+
+		// -- Check
+		let b58 = to_b58(&FX_UUID);
+		let expected = json!({
+			"id": b58,
+			"parent_id": to_b64url_nopad(&FX_UUID),
+			"item_ids": [b58, to_b58(&Uuid::nil())],
+		});
+		assert_eq!(value, expected);
+		assert_eq!(order, fx_order);
+		Ok(())
+	}
+
+	#[test]
+	fn test_serde_json_option_missing_and_null() -> Result<()> {
+		// -- Setup & Fixtures
+		let b58 = to_b58(&FX_UUID);
+		let fx_missing = json!({ "id": b58, "item_ids": [] });
+		let fx_null = json!({ "id": b58, "parent_id": null, "item_ids": [] });
+
+		// -- Exec
+		let order_missing: FxOrder = serde_json::from_value(fx_missing)?;
+		let order_null: FxOrder = serde_json::from_value(fx_null)?;
+
+		// -- Check
+		assert_eq!(order_missing.parent_id, None);
+		assert_eq!(order_null.parent_id, None);
+		Ok(())
+	}
+
+	#[test]
+	fn test_serde_json_lenient_ok() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_inputs = [
+			to_b58(&FX_UUID),
+			FX_UUID.hyphenated().to_string(),
+			FX_UUID.urn().to_string(),
+			to_b64url_nopad(&FX_UUID),
+		];
+
+		for input in fx_inputs {
+			// -- Exec
+			let lenient: FxLenient = serde_json::from_value(json!({ "id": input }))?;
+
+			// -- Check
+			assert_eq!(lenient.id, FX_UUID, "Wrong uuid for '{input}'");
+		}
+		// The module encoding is accepted even when not in `parse_any` (e.g., Crockford Base32).
+		let fx_b32 = crate::Encoding::B32.encode(&FX_UUID);
+		let lenient_b32: FxLenientB32 = serde_json::from_value(json!({ "id": fx_b32 }))?;
+		assert_eq!(lenient_b32.id, FX_UUID);
+		let lenient_b32: FxLenientB32 = serde_json::from_value(json!({ "id": to_b58(&FX_UUID) }))?;
+		assert_eq!(lenient_b32.id, FX_UUID);
+		assert!(serde_json::from_value::<FxLenient>(json!({ "id": fx_b32 })).is_err());
+		// Serialize stays in the module encoding.
+		assert_eq!(
+			serde_json::to_value(FxLenient { id: FX_UUID })?,
+			json!({ "id": to_b58(&FX_UUID) })
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_serde_json_strict_err() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_value = json!({ "id": FX_UUID.hyphenated().to_string(), "item_ids": [] });
+
+		// -- Exec
+		let res = serde_json::from_value::<FxOrder>(fx_value);
+
+		// -- Check
+		let err = res.err().ok_or("Should fail on hyphenated for a strict b58 field")?;
+		assert!(err.to_string().contains("InvalidLength"), "Unexpected error: {err}");
+		Ok(())
+	}
+
+	#[test]
+	fn test_serde_binary_raw_bytes() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_order = FxOrder {
+			id: FX_UUID,
+			parent_id: None,
+			item_ids: vec![FX_UUID],
+		};
+
+		// -- Exec & Check
+		assert_tokens(
+			&fx_order.compact(),
+			&[
+				Token::Struct {
+					name: "FxOrder",
+					len: 3,
+				},
+				Token::Str("id"),
+				Token::Bytes(&FX_BYTES),
+				Token::Str("parent_id"),
+				Token::None,
+				Token::Str("item_ids"),
+				Token::Seq { len: Some(1) },
+				Token::Bytes(&FX_BYTES),
+				Token::SeqEnd,
+				Token::StructEnd,
+			],
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_serde_id_roundtrip() -> Result<()> {
+		// -- Setup & Fixtures
+		struct Order;
+		let fx_id: Id<Order, B64UrlNoPad> = Id::from_uuid(FX_UUID);
+
+		// -- Exec
+		let value = serde_json::to_value(fx_id)?;
+		let id: Id<Order, B64UrlNoPad> = serde_json::from_value(value.clone())?;
+
+		// -- Check
+		assert_eq!(value, json!(to_b64url_nopad(&FX_UUID)));
+		assert_eq!(id, fx_id);
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -0,0 +1,311 @@
+//! Tamper-evident ids, a UUID with a truncated HMAC-SHA256 tag (requires the `signed` feature).
+//!
+//! For unauthenticated links (e.g., unsubscribe or invite accept), the id is only accepted
+//! if it was issued with the secret, so clients cannot forge or enumerate ids.
+//!
+//! ```ignore
+//! let signer = SignedId::new(2, &secret_v2)      // current key, id 2
+//!     .with_previous_key(1, &secret_v1)           // still verified during the rotation
+//!     .with_tag_len(16)?;
+//! let token = signer.encode(&invite.id);
+//! let invite_id = signer.verify_and_decode(&token)?;
+//! ```
+//!
+//! The encoded payload is `key_id (1 byte) | uuid (16 bytes) | tag (tag_len bytes)`, with the tag computed
+//! over the key id and the UUID. The UUID is not hidden (see the `obfuscate` feature for that).
+
+use crate::extra_base58::{decode_b58_bytes, encode_b58_bytes};
+use crate::{Encoding, Error, Result, support};
+use base64::{Engine as _, engine::general_purpose};
+use core::fmt;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Min tag length in bytes (64 bits).
+pub const SIGNED_ID_TAG_MIN_LEN: usize = 8;
+/// Max tag length in bytes (the full HMAC-SHA256).
+pub const SIGNED_ID_TAG_MAX_LEN: usize = 32;
+/// Default tag length in bytes (96 bits).
+pub const SIGNED_ID_TAG_DEFAULT_LEN: usize = 12;
+
+/// Key id and UUID bytes, before the tag.
+const SIGNED_LEN: usize = 1 + 16;
+const PAYLOAD_MAX_LEN: usize = SIGNED_LEN + SIGNED_ID_TAG_MAX_LEN;
+/// Decode buffer, larger than the max payload to report the actual length of slightly longer inputs.
+const DECODE_BUF_LEN: usize = 64;
+
+/// Signs and verifies ids with HMAC-SHA256, encoded using Base58 (default) or URL-safe Base64 without padding.
+///
+/// New ids are signed with the current key, and ids of previous keys (found by their key id byte)
+/// are still accepted, so keys can be rotated without invalidating issued links.
+#[derive(Clone)]
+pub struct SignedId {
+	current: SigningKey,
+	previous: Vec<SigningKey>,
+	tag_len: usize,
+	encoding: Encoding,
+}
+
+#[derive(Clone)]
+struct SigningKey {
+	key_id: u8,
+	mac: HmacSha256,
+}
+
+/// Constructors & Builder
+impl SignedId {
+	/// Creates a signer with the current `key_id` and `secret` (use at least 32 random bytes).
+	pub fn new(key_id: u8, secret: &[u8]) -> Self {
+		Self {
+			current: SigningKey::new(key_id, secret),
+			previous: Vec::new(),
+			tag_len: SIGNED_ID_TAG_DEFAULT_LEN,
+			encoding: Encoding::B58,
+		}
+	}
+
+	/// Adds a previous key, only used to verify ids issued before a rotation.
+	pub fn with_previous_key(mut self, key_id: u8, secret: &[u8]) -> Self {
+		self.previous.push(SigningKey::new(key_id, secret));
+		self
+	}
+
+	/// Sets the tag length in bytes, from 8 to 32 (12 by default).
+	///
+	/// Note: Changing it invalidates the issued ids (their length no longer matches).
+	pub fn with_tag_len(mut self, tag_len: usize) -> Result<Self> {
+		if !(SIGNED_ID_TAG_MIN_LEN..=SIGNED_ID_TAG_MAX_LEN).contains(&tag_len) {
+			return Err(Error::SignedIdTagLenOutOfRange { tag_len });
+		}
+		self.tag_len = tag_len;
+		Ok(self)
+	}
+
+	/// Sets the encoding, `Encoding::B58` (default) or `Encoding::B64UrlNoPad`.
+	pub fn with_encoding(mut self, encoding: Encoding) -> Result<Self> {
+		if !matches!(encoding, Encoding::B58 | Encoding::B64UrlNoPad) {
+			return Err(Error::UnsupportedEncoding { encoding });
+		}
+		self.encoding = encoding;
+		Ok(self)
+	}
+}
+
+/// Getters
+impl SignedId {
+	/// Key id of the current key, used to sign new ids.
+	pub fn key_id(&self) -> u8 {
+		self.current.key_id
+	}
+
+	pub fn tag_len(&self) -> usize {
+		self.tag_len
+	}
+
+	pub fn encoding(&self) -> Encoding {
+		self.encoding
+	}
+}
+
+/// Encode & Verify
+impl SignedId {
+	/// Signs `uuid` with the current key and encodes the payload.
+	pub fn encode(&self, uuid: &Uuid) -> String {
+		let mut payload = [0u8; PAYLOAD_MAX_LEN];
+		payload[0] = self.current.key_id;
+		payload[1..SIGNED_LEN].copy_from_slice(uuid.as_bytes());
+		let tag = self.current.tag(&payload[..SIGNED_LEN]);
+		let len = SIGNED_LEN + self.tag_len;
+		payload[SIGNED_LEN..len].copy_from_slice(&tag[..self.tag_len]);
+
+		match self.encoding {
+			Encoding::B64UrlNoPad => general_purpose::URL_SAFE_NO_PAD.encode(&payload[..len]),
+			_ => encode_b58_bytes(&payload[..len]),
+		}
+	}
+
+	/// Decodes a signed id and verifies its tag (in constant time), returning the UUID.
+	///
+	/// Returns the decode errors of the encoding, `Error::InvalidLength` (in payload bytes) for another tag length,
+	/// `Error::SignedIdUnknownKey` for a key id that is neither current nor previous,
+	/// or `Error::SignedIdInvalidTag` if the id was forged or altered.
+	pub fn verify_and_decode(&self, s: &str) -> Result<Uuid> {
+		let mut buf = [0u8; DECODE_BUF_LEN];
+		let payload = self.decode_payload(s, &mut buf)?;
+
+		let expected_len = SIGNED_LEN + self.tag_len;
+		if payload.len() != expected_len {
+			return Err(Error::InvalidLength {
+				encoding: "signed-id",
+				expected: expected_len,
+				actual: payload.len(),
+			});
+		}
+
+		let (signed, tag) = payload.split_at(SIGNED_LEN);
+		let key_id = signed[0];
+		let key = core::iter::once(&self.current)
+			.chain(self.previous.iter())
+			.find(|key| key.key_id == key_id)
+			.ok_or(Error::SignedIdUnknownKey { key_id })?;
+
+		let mut mac = key.mac.clone();
+		mac.update(signed);
+		mac.verify_truncated_left(tag).map_err(|_| Error::SignedIdInvalidTag)?;
+
+		support::from_slice_u8(&signed[1..], "signed-id")
+	}
+
+	fn decode_payload<'a>(&self, s: &str, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+		match self.encoding {
+			Encoding::B64UrlNoPad => {
+				let expected_len = ((SIGNED_LEN + self.tag_len) * 4).div_ceil(3);
+				let len = general_purpose::URL_SAFE_NO_PAD
+					.decode_slice(s, buf)
+					.map_err(|err| support::from_base64_err(err, s, "base64url-nopad", expected_len))?;
+				Ok(&buf[..len])
+			}
+			_ => decode_b58_bytes(s, buf),
+		}
+	}
+}
+
+impl SigningKey {
+	fn new(key_id: u8, secret: &[u8]) -> Self {
+		let mac = <HmacSha256 as Mac>::new_from_slice(secret).expect("HMAC should accept keys of any length");
+		Self { key_id, mac }
+	}
+
+	fn tag(&self, signed: &[u8]) -> [u8; SIGNED_ID_TAG_MAX_LEN] {
+		let mut mac = self.mac.clone();
+		mac.update(signed);
+		mac.finalize().into_bytes().into()
+	}
+}
+
+// Does not print the keys.
+impl fmt::Debug for SignedId {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let previous_key_ids: Vec<u8> = self.previous.iter().map(|key| key.key_id).collect();
+		f.debug_struct("SignedId")
+			.field("key_id", &self.current.key_id)
+			.field("previous_key_ids", &previous_key_ids)
+			.field("tag_len", &self.tag_len)
+			.field("encoding", &self.encoding)
+			.finish_non_exhaustive()
+	}
+}
+
+// region:    --- Tests
+
+#[cfg(test)]
+mod tests {
+	type Result<T> = core::result::Result<T, Box<dyn std::error::Error>>; // For tests.
+
+	use super::*;
+	use crate::new_v7;
+
+	const FX_SECRET_1: &[u8] = b"secret-1-at-least-32-bytes-long!";
+	const FX_SECRET_2: &[u8] = b"secret-2-at-least-32-bytes-long!";
+
+	#[test]
+	fn test_signed_encode_verify_roundtrip() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_uuid = new_v7();
+		let signer_b58 = SignedId::new(1, FX_SECRET_1);
+		let signer_b64u = SignedId::new(1, FX_SECRET_1)
+			.with_tag_len(32)?
+			.with_encoding(Encoding::B64UrlNoPad)?;
+
+		// -- Exec
+		let token_b58 = signer_b58.encode(&fx_uuid);
+		let token_b64u = signer_b64u.encode(&fx_uuid);
+
+		// -- Check
+		assert_eq!(signer_b58.verify_and_decode(&token_b58)?, fx_uuid);
+		assert_eq!(signer_b64u.verify_and_decode(&token_b64u)?, fx_uuid);
+		assert_eq!(token_b64u.len(), ((SIGNED_LEN + 32) * 4).div_ceil(3), "No padding");
+		assert_eq!(bs58::decode(&token_b58).into_vec()?.len(), SIGNED_LEN + 12);
+		Ok(())
+	}
+
+	#[test]
+	fn test_signed_key_rotation() -> Result<()> {
+		// -- Setup & Fixtures
+		let fx_uuid = new_v7();
+		let signer_old = SignedId::new(1, FX_SECRET_1);
+		let signer_new = SignedId::new(2, FX_SECRET_2).with_previous_key(1, FX_SECRET_1);
+		let signer_new_only = SignedId::new(2, FX_SECRET_2);
+		let old_token = signer_old.encode(&fx_uuid);
+
+		// -- Exec
+		let res_rotated = signer_new.verify_and_decode(&old_token);
+		let res_dropped = signer_new_only.verify_and_decode(&old_token);
+
+		// -- Check
+		assert_eq!(res_rotated?, fx_uuid);
+		assert!(matches!(res_dropped, Err(Error::SignedIdUnknownKey { key_id: 1 })));
+		assert_ne!(signer_new.encode(&fx_uuid), old_token, "New ids use the current key");
+		Ok(())
+	}
+
+	#[test]
+	fn test_signed_verify_err_tampered() -> Result<()> {
+		// -- Setup & Fixtures
+		let signer = SignedId::new(1, FX_SECRET_1).with_encoding(Encoding::B64UrlNoPad)?;
+		let forger = SignedId::new(1, b"guessed-secret").with_encoding(Encoding::B64UrlNoPad)?;
+		let token = signer.encode(&new_v7());
+		let mut payload = general_purpose::URL_SAFE_NO_PAD.decode(&token)?;
+		payload[5] ^= 0x01;
+		let fx_tampered = general_purpose::URL_SAFE_NO_PAD.encode(&payload);
+		let fx_short = general_purpose::URL_SAFE_NO_PAD.encode(&payload[..26]);
+
+		// -- Exec
+		let res_tampered = signer.verify_and_decode(&fx_tampered);
+		let res_forged = signer.verify_and_decode(&forger.encode(&new_v7()));
+		let res_short = signer.verify_and_decode(&fx_short);
+
+		// -- Check
+		assert!(matches!(res_tampered, Err(Error::SignedIdInvalidTag)));
+		assert!(matches!(res_forged, Err(Error::SignedIdInvalidTag)));
+		assert!(
+			matches!(
+				res_short,
+				Err(Error::InvalidLength {
+					encoding: "signed-id",
+					expected: 29,
+					actual: 26
+				})
+			),
+			"Got {res_short:?}"
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn test_signed_config_err() -> Result<()> {
+		// -- Exec
+		let res_tag_len = SignedId::new(1, FX_SECRET_1).with_tag_len(4);
+		let res_encoding = SignedId::new(1, FX_SECRET_1).with_encoding(Encoding::B32);
+
+		// -- Check
+		assert!(matches!(
+			res_tag_len,
+			Err(Error::SignedIdTagLenOutOfRange { tag_len: 4 })
+		));
+		assert!(matches!(
+			res_encoding,
+			Err(Error::UnsupportedEncoding {
+				encoding: Encoding::B32
+			})
+		));
+		let debug = format!("{:?}", SignedId::new(1, FX_SECRET_1));
+		assert!(!debug.contains("secret"), "Should not print the key: {debug}");
+		Ok(())
+	}
+}
+
+// endregion: --- Tests
@@ -1,11 +1,70 @@
 use crate::{Error, Result};
 use uuid::Uuid;
 
-pub fn from_vec_u8(decoded_bytes: Vec<u8>, error_context: &'static str) -> Result<Uuid> {
-	let bytes_array: [u8; 16] = decoded_bytes.try_into().map_err(|ex: Vec<u8>| Error::FailToDecode16U8 {
+/// Size of the stack buffer used to decode into bytes, large enough to report the actual length
+/// of slightly wrong inputs (longer ones fail with `Error::InvalidLength`).
+pub const DECODE_BUF_LEN: usize = 32;
+
+pub fn from_slice_u8(decoded_bytes: &[u8], error_context: &'static str) -> Result<Uuid> {
+	let bytes_array: [u8; 16] = decoded_bytes.try_into().map_err(|_| Error::FailToDecode16U8 {
 		context: error_context,
-		actual_length: ex.len(),
+		actual_length: decoded_bytes.len(),
 	})?;
 
 	Ok(Uuid::from_bytes(bytes_array))
 }
+
+/// Returns the encoded ASCII bytes as a `&str`.
+pub fn ascii_str(encoded: &[u8]) -> &str {
+	core::str::from_utf8(encoded).expect("Encoded UUID should be ASCII")
+}
+
+/// Copies the encoded ASCII bytes into `out`, returning the written `&str`.
+pub fn write_into<'a>(encoded: &[u8], out: &'a mut [u8], encoding: &'static str) -> Result<&'a str> {
+	let actual = out.len();
+	let dest = out.get_mut(..encoded.len()).ok_or(Error::BufferTooSmall {
+		encoding,
+		required: encoded.len(),
+		actual,
+	})?;
+	dest.copy_from_slice(encoded);
+
+	Ok(ascii_str(dest))
+}
+
+/// Maps a `base64` decode error into the structured `Error` variants.
+///
+/// `expected_len` is the encoded length of 16 bytes for this engine (e.g., 24 with padding, 22 without).
+pub fn from_base64_err(err: base64::DecodeSliceError, s: &str, encoding: &'static str, expected_len: usize) -> Error {
+	let err = match err {
+		base64::DecodeSliceError::DecodeError(err) => err,
+		base64::DecodeSliceError::OutputSliceTooSmall => {
+			return Error::InvalidLength {
+				encoding,
+				expected: expected_len,
+				actual: s.len(),
+			};
+		}
+	};
+
+	match err {
+		base64::DecodeError::InvalidByte(index, byte) | base64::DecodeError::InvalidLastSymbol(index, byte) => {
+			Error::InvalidCharacter {
+				encoding,
+				ch: char_at(s, index).unwrap_or(byte as char),
+				index,
+			}
+		}
+		base64::DecodeError::InvalidLength(_) => Error::InvalidLength {
+			encoding,
+			expected: expected_len,
+			actual: s.len(),
+		},
+		base64::DecodeError::InvalidPadding => Error::InvalidPadding { encoding },
+	}
+}
+
+/// Returns the char starting at the byte `index`, if any.
+fn char_at(s: &str, index: usize) -> Option<char> {
+	s.get(index..).and_then(|rest| rest.chars().next())
+}